@@ -0,0 +1,277 @@
+import random
+from dataclasses import dataclass, replace
+from typing import Callable, Dict, List, Optional, Tuple
+
+from utils.data_types import AlgorithmName, DistanceMatrix, ProgressCallback, Tour
+from utils.tour import tour_length
+from utils.data_loader import load_tsp_dataset, TspDataset
+from utils.monitoring import AlgorithmResult, ResultMonitor
+from utils.timing import Deadline, timed
+
+# Dolne ograniczenie odległości w heurystyce 1/d (chroni przed dzieleniem przez zero)
+MIN_DISTANCE = 1e-10
+
+
+@dataclass
+class AcoConfig:
+    """Parametry algorytmu mrówkowego."""
+
+    # Waga feromonu
+    alpha: float = 1.0
+    # Waga heurystyki 1/odległość
+    beta: float = 3.0
+    # Część feromonu odparowująca w każdej iteracji
+    evaporation_rate: float = 0.5
+    ant_count: int = 20
+    iterations: int = 100
+    # Zatrzymanie po tylu iteracjach bez poprawy najlepszej trasy (None - brak)
+    stagnation_limit: Optional[int] = None
+    # Limit czasu obliczeń w sekundach (None - brak)
+    max_duration_s: Optional[float] = None
+    # Czy liczyć heurystykę i feromon na macierzy przeskalowanej do maksimum 1.0
+    # (DistanceMatrix.normalized) - równowaga alpha/beta nie zależy wtedy od jednostek
+    normalize_distances: bool = False
+    seed: Optional[int] = None
+
+    def validate(self) -> None:
+        """Sprawdza poprawność parametrów."""
+        if self.alpha < 0 or self.beta < 0:
+            raise ValueError("alpha and beta must be >= 0")
+        if not 0.0 < self.evaporation_rate <= 1.0:
+            raise ValueError("evaporation_rate must be in (0, 1]")
+        if self.ant_count <= 0:
+            raise ValueError("ant_count must be > 0")
+        if self.iterations <= 0:
+            raise ValueError("iterations must be > 0")
+        if self.stagnation_limit is not None and self.stagnation_limit <= 0:
+            raise ValueError("stagnation_limit must be > 0 when provided")
+        if self.max_duration_s is not None and self.max_duration_s <= 0:
+            raise ValueError("max_duration_s must be > 0 when provided")
+
+    def to_parameters(self) -> Dict[str, str]:
+        """Zwraca parametry w postaci do zapisania w AlgorithmResult."""
+        return {
+            "alpha": str(self.alpha),
+            "beta": str(self.beta),
+            "evaporation_rate": str(self.evaporation_rate),
+            "ant_count": str(self.ant_count),
+            "iterations": str(self.iterations),
+            "stagnation_limit": str(self.stagnation_limit),
+            "max_duration_s": str(self.max_duration_s),
+            "normalize_distances": str(self.normalize_distances),
+            "seed": str(self.seed),
+        }
+
+
+def _construct_tour(
+    weights: List[List[float]], start_city: int, rng: random.Random
+) -> Tour:
+    """Buduje trasę jednej mrówki - kolejne miasto losowane proporcjonalnie do wag."""
+    n = len(weights)
+    tour = [start_city]
+    unvisited = set(range(n))
+    unvisited.discard(start_city)
+    current = start_city
+
+    while unvisited:
+        candidates = list(unvisited)
+        candidate_weights = [weights[current][city] for city in candidates]
+        total = sum(candidate_weights)
+        if total > 0:
+            next_city = rng.choices(candidates, weights=candidate_weights)[0]
+        else:
+            # Wszystkie wagi zaniknęły (underflow) - wybór losowy
+            next_city = rng.choice(candidates)
+        tour.append(next_city)
+        unvisited.discard(next_city)
+        current = next_city
+
+    return tour
+
+
+def ant_colony(
+    distance_matrix: DistanceMatrix,
+    config: AcoConfig,
+    progress: Optional[ProgressCallback] = None,
+    start_tour: Optional[Tour] = None,
+) -> Tuple[Tour, float, int, List[float], bool]:
+    """
+    Algorytm mrówkowy (Ant System) dla TSP:
+    - każda mrówka buduje trasę wg feromonu^alpha * (1/odległość)^beta
+    - feromon odparowuje (evaporation_rate), a każda mrówka dokłada 1/długość_trasy
+      na krawędziach swojej trasy
+
+    Zwraca (najlepsza_trasa, długość, iteracja_znalezienia_najlepszej,
+    najlepsza_dotychczasowa_długość_po_każdej_iteracji, przekroczono_limit_czasu).
+    Jeśli podano progress, jest wywoływany po każdej iteracji z (iteracja, najlepsza_długość).
+    Jeśli ustawiono config.stagnation_limit, algorytm kończy się po tylu iteracjach
+    bez poprawy (liczba wykonanych iteracji to długość zwróconej historii); podobnie
+    po przekroczeniu config.max_duration_s.
+    Jeśli podano start_tour, jest ona początkową najlepszą trasą (best_iteration 0,
+    dopóki żadna mrówka jej nie poprawi), a na jej krawędziach przed pierwszą iteracją
+    odkładany jest feromon jak dla jednej mrówki. Bez start_tour zwracana jest zawsze
+    trasa zbudowana przez mrówkę.
+    Przy config.normalize_distances obliczenia odbywają się na macierzy
+    znormalizowanej, a zwracane długości są przeliczane na jednostki oryginalne.
+    Dla pustej instancji zwraca pustą trasę o długości 0 bez wykonywania iteracji.
+    """
+    config.validate()
+    deadline = Deadline(config.max_duration_s)
+
+    n = len(distance_matrix)
+    rng = random.Random(config.seed)
+    if n == 0:
+        return [], 0.0, 0, [], False
+    original_matrix = distance_matrix
+    if config.normalize_distances and n > 1:
+        distance_matrix = distance_matrix.normalized()
+
+    eta = [
+        [1.0 / max(distance_matrix[i][j], MIN_DISTANCE) if i != j else 0.0 for j in range(n)]
+        for i in range(n)
+    ]
+    pheromone = [[1.0] * n for _ in range(n)]
+
+    # Bez start_tour najlepszą trasą zostaje najlepsza mrówka pierwszej iteracji
+    # (pierwsza iteracja zawsze się wykonuje, a ant_count > 0)
+    best_tour: Tour = []
+    best_len = float("inf")
+    if start_tour is not None:
+        best_tour = start_tour.copy()
+        best_len = tour_length(best_tour, distance_matrix)
+        deposit = 1.0 / max(best_len, MIN_DISTANCE)
+        for k in range(n):
+            a, b = best_tour[k], best_tour[(k + 1) % n]
+            pheromone[a][b] += deposit
+            pheromone[b][a] += deposit
+    best_iteration = 0
+    history: List[float] = []
+    timed_out = False
+
+    for iteration in range(1, config.iterations + 1):
+        if iteration > 1 and deadline.expired():
+            timed_out = True
+            break
+        weights = [
+            [(pheromone[i][j] ** config.alpha) * (eta[i][j] ** config.beta) for j in range(n)]
+            for i in range(n)
+        ]
+
+        tours = [_construct_tour(weights, rng.randrange(n), rng) for _ in range(config.ant_count)]
+        lengths = [tour_length(t, distance_matrix) for t in tours]
+
+        for tour, length in zip(tours, lengths):
+            if length < best_len:
+                best_len = length
+                best_tour = tour
+                best_iteration = iteration
+
+        keep = 1.0 - config.evaporation_rate
+        for i in range(n):
+            for j in range(n):
+                pheromone[i][j] *= keep
+
+        for tour, length in zip(tours, lengths):
+            deposit = 1.0 / max(length, MIN_DISTANCE)
+            for k in range(n):
+                a, b = tour[k], tour[(k + 1) % n]
+                pheromone[a][b] += deposit
+                pheromone[b][a] += deposit
+
+        history.append(best_len)
+        if progress is not None:
+            progress(iteration, best_len)
+        if (
+            config.stagnation_limit is not None
+            and iteration - best_iteration >= config.stagnation_limit
+        ):
+            break
+
+    # Długość liczona od nowa w jednostkach oryginalnych, bez błędu skalowania
+    best_len = tour_length(best_tour, original_matrix)
+    history = [distance_matrix.denormalize(length) for length in history]
+    return best_tour, best_len, best_iteration, history, timed_out
+
+
+def aco_solver(
+    distance_matrix: DistanceMatrix,
+    config: AcoConfig,
+    result: AlgorithmResult,
+    progress: Optional[ProgressCallback] = None,
+    initial: Optional[Tour] = None,
+) -> Callable[[], AlgorithmResult]:
+    """
+    Zapisuje parametry config w wyniku i zwraca funkcję uruchamiającą kolonię mrówek
+    (z metryką best_iteration); wspólna dla run_aco i polecenia solve.
+    """
+    for key, value in config.to_parameters().items():
+        result.with_parameter(key, value)
+
+    def solve() -> AlgorithmResult:
+        best_tour, best_len, best_iteration, history, timed_out = ant_colony(
+            distance_matrix, config, progress, initial
+        )
+        result.set_result(best_len, best_tour)
+        result.set_iterations(len(history))
+        stopped_early = not timed_out and len(history) < config.iterations
+        result.with_metric("stopped_early", float(stopped_early))
+        result.with_metric("timed_out", float(timed_out))
+        result.with_convergence(history)
+        result.with_metric("best_iteration", float(best_iteration))
+        return result
+
+    return solve
+
+
+def run_aco(
+    dataset: TspDataset = TspDataset.TSP_48,
+    config: Optional[AcoConfig] = None,
+    progress: Optional[ProgressCallback] = None,
+    initial: Optional[Tour] = None,
+    initial_source: Optional[str] = None,
+) -> AlgorithmResult:
+    """
+    Uruchamia algorytm mrówkowy i zapisuje wyniki.
+
+    Args:
+        dataset: Zbiór danych TSP do przetworzenia
+        config: Parametry algorytmu (domyślnie AcoConfig()); jeśli config.seed
+                jest None, ziarno jest losowane i zapisywane w wyniku
+        progress: Opcjonalna funkcja wywoływana po każdej iteracji
+        initial: Trasa startowa (np. route wcześniejszego wyniku) dołączana do
+                 rozwiązań (z feromonem na jej krawędziach)
+        initial_source: Skąd pochodzi initial (zapisywane w parametrach wyniku)
+
+    Raises:
+        RouteError: Jeśli initial nie jest permutacją miast zbioru danych
+    """
+    config = config or AcoConfig()
+    if config.seed is None:
+        config = replace(config, seed=random.randrange(2**32))
+    distance_matrix = load_tsp_dataset(dataset)
+
+    result = AlgorithmResult.new(AlgorithmName.ACO.value)
+    result.with_dataset(dataset)
+    result.with_content_hash(distance_matrix)
+    solve = aco_solver(distance_matrix, config, result, progress, initial)
+    if initial is not None:
+        result.with_warm_start(initial, len(distance_matrix), initial_source)
+
+    print("Uruchamianie algorytmu mrówkowego...")
+
+    timed(solve)
+
+    print("Algorytm mrówkowy zakończony.")
+
+    monitor = ResultMonitor()
+    filename = monitor.save_result(result)
+    print(f"Wyniki zapisane do: {filename}")
+    print(f"Najlepsza długość trasy: {result.route_length:.2f}")
+    print(f"Czas wykonania: {result.execution_time_s:.3f} s")
+
+    return result
+
+
+if __name__ == "__main__":
+    for dataset in (TspDataset.TSP_48, TspDataset.TSP_76, TspDataset.TSP_127):
+        run_aco(dataset)
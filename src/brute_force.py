@@ -0,0 +1,135 @@
+from typing import Callable, List, Tuple
+
+from utils.data_types import AlgorithmName, DistanceMatrix, Tour
+from utils.tour import tour_length
+from utils.data_loader import load_tsp_dataset, TspDataset
+from utils.monitoring import AlgorithmResult, ResultMonitor
+from utils.timing import timed
+
+# Największa instancja obsługiwana przez przegląd zupełny ((n-1)! tras: 10! ≈ 3.6 mln)
+BRUTE_FORCE_MAX_CITIES = 11
+
+
+def brute_force(distance_matrix: DistanceMatrix) -> Tuple[Tour, float, int]:
+    """
+    Przegląd zupełny: trasa optymalna spośród wszystkich permutacji z ustalonym
+    miastem startowym 0 ((n-1)! tras). Gałęzie, których częściowa długość nie jest
+    krótsza od najlepszej znalezionej trasy, są pomijane - wynik pozostaje dokładny,
+    także dla macierzy asymetrycznych.
+
+    Zwraca (trasa_optymalna, długość, liczba_ocenionych_pełnych_tras).
+
+    Raises:
+        ValueError: Jeśli instancja ma więcej niż BRUTE_FORCE_MAX_CITIES miast
+    """
+    n = len(distance_matrix)
+    if n > BRUTE_FORCE_MAX_CITIES:
+        raise ValueError(
+            f"Brute force supports at most {BRUTE_FORCE_MAX_CITIES} cities, got {n}"
+        )
+    if n <= 2:
+        tour = list(range(n))
+        return tour, tour_length(tour, distance_matrix), 1 if n else 0
+
+    best_tour: Tour = list(range(n))
+    best_len = tour_length(best_tour, distance_matrix)
+    evaluated = 0
+    path: List[int] = [0]
+    visited = [False] * n
+    visited[0] = True
+
+    def extend(length: float) -> None:
+        nonlocal best_tour, best_len, evaluated
+        current = path[-1]
+        if len(path) == n:
+            evaluated += 1
+            total = length + distance_matrix[current][0]
+            if total < best_len:
+                best_len = total
+                best_tour = path.copy()
+            return
+        for city in range(1, n):
+            if visited[city]:
+                continue
+            next_length = length + distance_matrix[current][city]
+            if next_length >= best_len:
+                continue
+            visited[city] = True
+            path.append(city)
+            extend(next_length)
+            path.pop()
+            visited[city] = False
+
+    extend(0.0)
+    return best_tour, best_len, evaluated
+
+
+def brute_force_solver(
+    distance_matrix: DistanceMatrix, result: AlgorithmResult
+) -> Callable[[], AlgorithmResult]:
+    """
+    Zwraca funkcję rozwiązującą instancję przeglądem zupełnym i uzupełniającą wynik
+    (trasa optymalna, tours_evaluated); używana przez brute_force_result i polecenie
+    solve.
+    """
+    def solve() -> AlgorithmResult:
+        best_tour, best_len, evaluated = brute_force(distance_matrix)
+        result.set_result(best_len, best_tour)
+        result.set_iterations(evaluated)
+        result.with_metric("tours_evaluated", float(evaluated))
+        return result
+
+    return solve
+
+
+def brute_force_result(distance_matrix: DistanceMatrix) -> AlgorithmResult:
+    """
+    Rozwiązuje małą instancję dokładnie (brute_force) i zwraca wynik z trasą optymalną
+    i zmierzonym czasem (bez zapisu).
+
+    Raises:
+        ValueError: Jeśli instancja ma więcej niż BRUTE_FORCE_MAX_CITIES miast
+    """
+    result = AlgorithmResult.new(AlgorithmName.BRUTE_FORCE.value)
+    result.with_num_cities(len(distance_matrix))
+    result.with_content_hash(distance_matrix)
+    return timed(brute_force_solver(distance_matrix, result))
+
+
+def run_brute_force(
+    dataset: TspDataset = TspDataset.TSP_48, num_cities: int = BRUTE_FORCE_MAX_CITIES
+) -> AlgorithmResult:
+    """
+    Rozwiązuje dokładnie podinstancję złożoną z pierwszych num_cities miast zbioru
+    danych i zapisuje wynik.
+
+    Args:
+        dataset: Zbiór danych TSP, z którego brana jest podinstancja
+        num_cities: Liczba pierwszych miast zbioru danych
+
+    Raises:
+        ValueError: Jeśli num_cities > BRUTE_FORCE_MAX_CITIES
+    """
+    full_matrix = load_tsp_dataset(dataset)
+    distance_matrix = DistanceMatrix([row[:num_cities] for row in full_matrix[:num_cities]])
+
+    print("Uruchamianie przeglądu zupełnego...")
+
+    result = brute_force_result(distance_matrix)
+    result.with_instance(f"{dataset.value}[:{num_cities}]")
+    result.with_parameter("num_cities", str(num_cities))
+
+    print("Przegląd zupełny zakończony.")
+
+    monitor = ResultMonitor()
+    filename = monitor.save_result(result)
+    print(f"Wyniki zapisane do: {filename}")
+    print(f"Optymalna długość trasy: {result.route_length:.2f}")
+    print(f"Czas wykonania: {result.execution_time_s:.3f} s")
+
+    return result
+
+
+if __name__ == "__main__":
+    for dataset in (TspDataset.TSP_48, TspDataset.TSP_76, TspDataset.TSP_127):
+        run_brute_force(dataset, num_cities=10)
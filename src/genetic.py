@@ -0,0 +1,605 @@
+import random
+from concurrent.futures import ProcessPoolExecutor
+from dataclasses import dataclass, replace
+from enum import Enum
+from itertools import accumulate
+from typing import Callable, Dict, List, Optional, Tuple
+
+from utils.data_types import AlgorithmName, DistanceMatrix, ProgressCallback, Tour
+from utils.tour import mean_route_distance, random_tour, tour_length
+from utils.data_loader import load_tsp_dataset, TspDataset
+from utils.monitoring import AlgorithmResult, ResultMonitor
+from utils.timing import Deadline, timed
+from nearest_neighbor import nearest_neighbor
+
+# Dolne ograniczenie długości trasy w wagach ruletki 1/długość (chroni przed dzieleniem
+# przez zero)
+MIN_ROUTE_LENGTH = 1e-10
+
+
+class CrossoverType(Enum):
+    """Dostępne operatory krzyżowania."""
+
+    # Order Crossover
+    OX = "ox"
+    # Partially Mapped Crossover
+    PMX = "pmx"
+
+
+class SelectionStrategy(Enum):
+    """Dostępne metody selekcji rodziców."""
+
+    # Najkrótsza trasa spośród tournament_size losowych osobników
+    TOURNAMENT = "tournament"
+    # Koło ruletki - prawdopodobieństwo proporcjonalne do 1 / długość_trasy
+    ROULETTE = "roulette"
+    # Selekcja rankingowa (liniowa) z naciskiem selekcyjnym rank_pressure
+    RANK = "rank"
+
+
+@dataclass
+class GeneticConfig:
+    """Parametry algorytmu genetycznego."""
+
+    population_size: int = 100
+    generations: int = 500
+    crossover_rate: float = 0.9
+    mutation_rate: float = 0.02
+    crossover: CrossoverType = CrossoverType.OX
+    selection: SelectionStrategy = SelectionStrategy.TOURNAMENT
+    tournament_size: int = 3
+    # Nacisk selekcji rankingowej w [1, 2]: stosunek prawdopodobieństwa wyboru
+    # najlepszego osobnika do średniego (1 - wybór jednostajny)
+    rank_pressure: float = 1.5
+    # Liczba najlepszych osobników przenoszonych bez zmian do następnego pokolenia
+    # (elitaryzm; przycinana do population_size)
+    elite_count: int = 0
+    # Zatrzymanie po tylu pokoleniach bez poprawy najlepszej trasy (None - brak)
+    stagnation_limit: Optional[int] = None
+    # Część populacji początkowej z tras najbliższego sąsiada (przycinana do [0, 1])
+    heuristic_seed_fraction: float = 0.0
+    # Próg średniej odległości route_distance w populacji, poniżej którego część
+    # populacji jest zastępowana losowymi trasami (None - bez restartów)
+    diversity_restart_threshold: Optional[float] = None
+    # Część populacji (najgorsze osobniki) zastępowana przy restarcie
+    diversity_restart_fraction: float = 0.5
+    # Model wyspowy: liczba wysp, każda z własną populacją population_size osobników
+    # (1 - jedna populacja)
+    islands: int = 1
+    # Co tyle pokoleń najlepsze osobniki wysp migrują do sąsiedniej wyspy (pierścień)
+    migration_interval: int = 50
+    # Liczba migrujących osobników (zastępują najgorsze osobniki wyspy docelowej)
+    migrants: int = 1
+    # Czy wyspy ewoluują równolegle w osobnych procesach (wynik jest taki sam)
+    parallel_islands: bool = True
+    # Limit czasu obliczeń w sekundach (None - brak)
+    max_duration_s: Optional[float] = None
+    seed: Optional[int] = None
+
+    def validate(self) -> None:
+        """Sprawdza poprawność parametrów."""
+        if self.population_size < 2:
+            raise ValueError("population_size must be >= 2")
+        if self.islands < 1:
+            raise ValueError("islands must be >= 1")
+        if self.migration_interval < 1:
+            raise ValueError("migration_interval must be >= 1")
+        if not 0 <= self.migrants < self.population_size:
+            raise ValueError("migrants must be in [0, population_size)")
+        if self.islands > 1 and self.diversity_restart_threshold is not None:
+            raise ValueError("diversity restarts are not supported with islands > 1")
+        if self.generations <= 0:
+            raise ValueError("generations must be > 0")
+        if not 0.0 <= self.crossover_rate <= 1.0:
+            raise ValueError("crossover_rate must be in [0, 1]")
+        if not 0.0 <= self.mutation_rate <= 1.0:
+            raise ValueError("mutation_rate must be in [0, 1]")
+        if self.tournament_size < 1:
+            raise ValueError("tournament_size must be >= 1")
+        if self.elite_count < 0:
+            raise ValueError("elite_count must be >= 0")
+        if not 1.0 <= self.rank_pressure <= 2.0:
+            raise ValueError("rank_pressure must be in [1, 2]")
+        if self.stagnation_limit is not None and self.stagnation_limit <= 0:
+            raise ValueError("stagnation_limit must be > 0 when provided")
+        if self.diversity_restart_threshold is not None and self.diversity_restart_threshold < 0:
+            raise ValueError("diversity_restart_threshold must be >= 0 when provided")
+        if not 0.0 < self.diversity_restart_fraction <= 1.0:
+            raise ValueError("diversity_restart_fraction must be in (0, 1]")
+        if self.max_duration_s is not None and self.max_duration_s <= 0:
+            raise ValueError("max_duration_s must be > 0 when provided")
+
+    def to_parameters(self) -> Dict[str, str]:
+        """Zwraca parametry w postaci do zapisania w AlgorithmResult."""
+        return {
+            "population_size": str(self.population_size),
+            "generations": str(self.generations),
+            "crossover_rate": str(self.crossover_rate),
+            "mutation_rate": str(self.mutation_rate),
+            "crossover": self.crossover.value,
+            "selection": self.selection.value,
+            "tournament_size": str(self.tournament_size),
+            "rank_pressure": str(self.rank_pressure),
+            "elite_count": str(self.elite_count),
+            "stagnation_limit": str(self.stagnation_limit),
+            "heuristic_seed_fraction": str(self.heuristic_seed_fraction),
+            "diversity_restart_threshold": str(self.diversity_restart_threshold),
+            "diversity_restart_fraction": str(self.diversity_restart_fraction),
+            "islands": str(self.islands),
+            "migration_interval": str(self.migration_interval),
+            "migrants": str(self.migrants),
+            "parallel_islands": str(self.parallel_islands),
+            "max_duration_s": str(self.max_duration_s),
+            "seed": str(self.seed),
+        }
+
+    def heuristic_seed_count(self, num_cities: int) -> int:
+        """
+        Zwraca liczbę osobników populacji początkowej tworzonych heurystyką
+        najbliższego sąsiada (każdy z innego miasta startowego, więc co najwyżej num_cities).
+        """
+        fraction = min(max(self.heuristic_seed_fraction, 0.0), 1.0)
+        return min(round(fraction * self.population_size), num_cities)
+
+
+def order_crossover(parent1: Tour, parent2: Tour, rng: random.Random) -> Tour:
+    """
+    Krzyżowanie OX: potomek dziedziczy losowy fragment z parent1, a pozostałe
+    miasta uzupełniane są w kolejności z parent2 (zaczynając za fragmentem).
+    """
+    n = len(parent1)
+    if n == 0:
+        return []
+    a, b = sorted(rng.sample(range(n), 2)) if n >= 2 else (0, 0)
+
+    child: List[Optional[int]] = [None] * n
+    child[a : b + 1] = parent1[a : b + 1]
+    inherited = set(parent1[a : b + 1])
+
+    position = (b + 1) % n
+    for k in range(n):
+        city = parent2[(b + 1 + k) % n]
+        if city in inherited:
+            continue
+        child[position] = city
+        position = (position + 1) % n
+
+    return child
+
+
+def pmx_crossover(parent1: Tour, parent2: Tour, rng: random.Random) -> Tour:
+    """
+    Krzyżowanie PMX: potomek dziedziczy losowy fragment z parent1, a pozostałe pozycje
+    z parent2. Miasto z parent2, które występuje już we fragmencie, zastępowane jest
+    zgodnie z odwzorowaniem fragmentu parent1[i] -> parent2[i] (powtarzanym, aż
+    wynik znajdzie się poza fragmentem), więc potomek jest poprawną permutacją.
+    """
+    n = len(parent1)
+    if n == 0:
+        return []
+    a, b = sorted(rng.sample(range(n), 2)) if n >= 2 else (0, 0)
+
+    child = parent2.copy()
+    child[a : b + 1] = parent1[a : b + 1]
+    # Miasto z fragmentu parent1 -> miasto parent2 na tej samej pozycji
+    mapping = {parent1[i]: parent2[i] for i in range(a, b + 1)}
+
+    for i in list(range(a)) + list(range(b + 1, n)):
+        city = parent2[i]
+        while city in mapping:
+            city = mapping[city]
+        child[i] = city
+
+    return child
+
+
+# Operator krzyżowania dla każdego typu z CrossoverType
+CROSSOVER_OPERATORS = {
+    CrossoverType.OX: order_crossover,
+    CrossoverType.PMX: pmx_crossover,
+}
+
+
+def swap_mutation(tour: Tour, rng: random.Random) -> None:
+    """Mutacja swap: zamienia miejscami dwa losowe miasta (w miejscu)."""
+    if len(tour) < 2:
+        return
+    i, j = rng.sample(range(len(tour)), 2)
+    tour[i], tour[j] = tour[j], tour[i]
+
+
+def _tournament_select(
+    population: List[Tour], lengths: List[float], size: int, rng: random.Random
+) -> Tour:
+    """Selekcja turniejowa: wybiera najkrótszą trasę spośród 'size' losowych osobników."""
+    contenders = [rng.randrange(len(population)) for _ in range(size)]
+    winner = min(contenders, key=lambda idx: lengths[idx])
+    return population[winner]
+
+
+def _roulette_weights(lengths: List[float]) -> List[float]:
+    """Wagi koła ruletki: krótsza trasa - większa waga (1 / długość)."""
+    return [1.0 / max(length, MIN_ROUTE_LENGTH) for length in lengths]
+
+
+def _rank_weights(lengths: List[float], pressure: float) -> List[float]:
+    """
+    Wagi selekcji rankingowej liniowej: osobnik na pozycji r (0 - najdłuższa trasa,
+    N-1 - najkrótsza) dostaje (2 - s) / N + 2 r (s - 1) / (N (N - 1)).
+    """
+    size = len(lengths)
+    weights = [0.0] * size
+    worst_first = sorted(range(size), key=lambda idx: lengths[idx], reverse=True)
+    for rank, idx in enumerate(worst_first):
+        weights[idx] = (2 - pressure) / size + 2 * rank * (pressure - 1) / (size * (size - 1))
+    return weights
+
+
+def _make_selector(
+    population: List[Tour], lengths: List[float], config: GeneticConfig, rng: random.Random
+) -> Callable[[], Tour]:
+    """Zwraca funkcję wybierającą jednego rodzica wg config.selection."""
+    if config.selection == SelectionStrategy.TOURNAMENT:
+        return lambda: _tournament_select(population, lengths, config.tournament_size, rng)
+
+    if config.selection == SelectionStrategy.ROULETTE:
+        weights = _roulette_weights(lengths)
+    else:
+        weights = _rank_weights(lengths, config.rank_pressure)
+    cumulative = list(accumulate(weights))
+    return lambda: rng.choices(population, cum_weights=cumulative)[0]
+
+
+def _restart_population(
+    population: List[Tour],
+    lengths: List[float],
+    fraction: float,
+    distance_matrix: DistanceMatrix,
+    rng: random.Random,
+) -> None:
+    """
+    Zastępuje (w miejscu) część populacji najgorszych osobników losowymi trasami.
+    Najlepszy osobnik nigdy nie jest zastępowany.
+    """
+    n = len(distance_matrix)
+    count = min(max(1, round(fraction * len(population))), len(population) - 1)
+    worst_first = sorted(range(len(population)), key=lambda idx: lengths[idx], reverse=True)
+    for idx in worst_first[:count]:
+        population[idx] = random_tour(n, rng)
+        lengths[idx] = tour_length(population[idx], distance_matrix)
+
+
+def _initial_population(
+    distance_matrix: DistanceMatrix,
+    config: GeneticConfig,
+    rng: random.Random,
+    start_tour: Optional[Tour],
+) -> Tuple[List[Tour], List[float]]:
+    """Populacja początkowa (trasy najbliższego sąsiada, losowe i start_tour) z długościami."""
+    n = len(distance_matrix)
+    seed_count = config.heuristic_seed_count(n)
+    start_cities = rng.sample(range(n), seed_count)
+    population = [nearest_neighbor(distance_matrix, city)[0] for city in start_cities]
+    population += [random_tour(n, rng) for _ in range(config.population_size - seed_count)]
+    if start_tour is not None:
+        population[-1] = start_tour.copy()
+    return population, [tour_length(t, distance_matrix) for t in population]
+
+
+def _next_generation(
+    population: List[Tour],
+    lengths: List[float],
+    config: GeneticConfig,
+    distance_matrix: DistanceMatrix,
+    rng: random.Random,
+) -> Tuple[List[Tour], List[float]]:
+    """Jedno pokolenie: elita, selekcja, krzyżowanie i mutacja; zwraca nową populację."""
+    elite_count = min(config.elite_count, config.population_size)
+    select = _make_selector(population, lengths, config, rng)
+    elite = sorted(range(len(population)), key=lambda idx: lengths[idx])[:elite_count]
+    offspring: List[Tour] = [population[idx].copy() for idx in elite]
+    while len(offspring) < config.population_size:
+        parent1 = select()
+        parent2 = select()
+
+        if rng.random() < config.crossover_rate:
+            child = CROSSOVER_OPERATORS[config.crossover](parent1, parent2, rng)
+        else:
+            child = parent1.copy()
+
+        if rng.random() < config.mutation_rate:
+            swap_mutation(child, rng)
+
+        offspring.append(child)
+
+    return offspring, [tour_length(t, distance_matrix) for t in offspring]
+
+
+# Stan wyspy przekazywany między procesami: (populacja, długości, stan generatora)
+IslandState = Tuple[List[Tour], List[float], tuple]
+
+
+def _evolve_island(
+    task: Tuple[DistanceMatrix, GeneticConfig, IslandState, int]
+) -> Tuple[IslandState, List[float], Tour, float]:
+    """
+    Ewoluuje jedną wyspę przez zadaną liczbę pokoleń (funkcja z poziomu modułu, aby dała
+    się uruchomić w puli procesów). Zwraca (nowy_stan, najlepsza_długość_każdego_pokolenia,
+    najlepsza_trasa_epoki, jej_długość).
+    """
+    distance_matrix, config, (population, lengths, rng_state), generations = task
+    rng = random.Random()
+    rng.setstate(rng_state)
+
+    best_len = float("inf")
+    best_tour: Tour = []
+    generation_best: List[float] = []
+    for _ in range(generations):
+        population, lengths = _next_generation(population, lengths, config, distance_matrix, rng)
+        idx = min(range(len(population)), key=lambda i: lengths[i])
+        generation_best.append(lengths[idx])
+        if lengths[idx] < best_len:
+            best_len = lengths[idx]
+            best_tour = population[idx].copy()
+    return (population, lengths, rng.getstate()), generation_best, best_tour, best_len
+
+
+def _migrate(states: List[IslandState], migrants: int) -> None:
+    """
+    Migracja w pierścieniu (w miejscu): kopie migrants najlepszych osobników wyspy i
+    zastępują najgorsze osobniki wyspy (i + 1) mod liczba_wysp.
+    """
+    emigrants = []
+    for population, lengths, _ in states:
+        best_first = sorted(range(len(population)), key=lambda idx: lengths[idx])[:migrants]
+        emigrants.append([(population[idx].copy(), lengths[idx]) for idx in best_first])
+
+    for i, arrivals in enumerate(emigrants):
+        population, lengths, _ = states[(i + 1) % len(states)]
+        worst_first = sorted(range(len(population)), key=lambda idx: lengths[idx], reverse=True)
+        for idx, (tour, length) in zip(worst_first, arrivals):
+            population[idx] = tour
+            lengths[idx] = length
+
+
+def _island_model(
+    distance_matrix: DistanceMatrix,
+    config: GeneticConfig,
+    progress: Optional[ProgressCallback],
+    start_tour: Optional[Tour],
+) -> Tuple[Tour, float, List[float], bool, List[float], List[int]]:
+    """
+    Model wyspowy algorytmu genetycznego (config.islands > 1). Każda wyspa ma własny
+    generator z ziarnem pochodnym od config.seed, a wyspy synchronizowane są co
+    config.migration_interval pokoleń (epoka), więc wynik nie zależy od tego, czy
+    wyspy działają równolegle. Limit czasu i stagnation_limit sprawdzane są między
+    epokami. Wynik ma ten sam kształt co genetic_algorithm (bez serii różnorodności
+    i restartów).
+    """
+    deadline = Deadline(config.max_duration_s)
+    seeder = random.Random(config.seed)
+    rngs = [random.Random(seeder.randrange(2**32)) for _ in range(config.islands)]
+
+    states: List[IslandState] = []
+    for i, rng in enumerate(rngs):
+        population, lengths = _initial_population(
+            distance_matrix, config, rng, start_tour if i == 0 else None
+        )
+        states.append((population, lengths, rng.getstate()))
+
+    best_tour: Tour = []
+    best_len = float("inf")
+    for population, lengths, _ in states:
+        idx = min(range(len(population)), key=lambda i: lengths[i])
+        if lengths[idx] < best_len:
+            best_len = lengths[idx]
+            best_tour = population[idx].copy()
+
+    history: List[float] = []
+    no_improve = 0
+    timed_out = False
+    executor = ProcessPoolExecutor(max_workers=config.islands) if config.parallel_islands else None
+    try:
+        while len(history) < config.generations:
+            if history and deadline.expired():
+                timed_out = True
+                break
+            epoch = min(config.migration_interval, config.generations - len(history))
+            tasks = [(distance_matrix, config, state, epoch) for state in states]
+            if executor is not None:
+                outcomes = list(executor.map(_evolve_island, tasks))
+            else:
+                outcomes = [_evolve_island(task) for task in tasks]
+            states = [state for state, _, _, _ in outcomes]
+
+            for step in range(epoch):
+                generation_best = min(best[step] for _, best, _, _ in outcomes)
+                if generation_best < best_len:
+                    best_len = generation_best
+                    no_improve = 0
+                else:
+                    no_improve += 1
+                history.append(best_len)
+                if progress is not None:
+                    progress(len(history), best_len)
+            for _, _, tour, length in outcomes:
+                if length <= best_len and tour:
+                    best_tour = tour
+
+            if config.stagnation_limit is not None and no_improve >= config.stagnation_limit:
+                break
+            if len(history) < config.generations:
+                _migrate(states, config.migrants)
+    finally:
+        if executor is not None:
+            executor.shutdown()
+
+    return best_tour, best_len, history, timed_out, [], []
+
+
+def genetic_algorithm(
+    distance_matrix: DistanceMatrix,
+    config: GeneticConfig,
+    progress: Optional[ProgressCallback] = None,
+    start_tour: Optional[Tour] = None,
+) -> Tuple[Tour, float, List[float], bool, List[float], List[int]]:
+    """
+    Algorytm genetyczny dla TSP:
+    - populacja losowych permutacji, częściowo zastępowanych trasami najbliższego
+      sąsiada z różnych miast startowych (config.heuristic_seed_fraction);
+      start_tour, jeśli podano, zastępuje ostatniego osobnika populacji początkowej
+    - selekcja (config.selection: turniejowa, ruletka lub rankingowa),
+      krzyżowanie (config.crossover), mutacja swap
+    - pełna wymiana pokolenia, z wyjątkiem config.elite_count najlepszych osobników
+      przenoszonych bez zmian (elitaryzm)
+    - różnorodność populacji (średnia route_distance między parami tras) mierzona
+      po każdym pokoleniu; poniżej config.diversity_restart_threshold część
+      najgorszych osobników (config.diversity_restart_fraction) zastępowana jest
+      losowymi trasami
+    - dla config.islands > 1 model wyspowy z migracją (patrz _island_model)
+
+    Zwraca (najlepsza_trasa, długość, najlepsza_dotychczasowa_długość_po_każdym_pokoleniu,
+    przekroczono_limit_czasu, różnorodność_po_każdym_pokoleniu, pokolenia_z_restartem).
+    Dla tego samego config.seed wynik jest powtarzalny (o ile nie przerwał go
+    config.max_duration_s). Jeśli ustawiono
+    config.stagnation_limit, algorytm kończy się po tylu pokoleniach bez poprawy
+    (liczba wykonanych pokoleń to długość zwróconej historii).
+    Jeśli podano progress, jest wywoływany po każdym pokoleniu z (pokolenie, najlepsza_długość).
+    """
+    config.validate()
+    if config.islands > 1:
+        return _island_model(distance_matrix, config, progress, start_tour)
+    deadline = Deadline(config.max_duration_s)
+
+    rng = random.Random(config.seed)
+    population, lengths = _initial_population(distance_matrix, config, rng, start_tour)
+
+    best_idx = min(range(len(population)), key=lambda idx: lengths[idx])
+    best_tour = population[best_idx].copy()
+    best_len = lengths[best_idx]
+    history: List[float] = []
+    diversity: List[float] = []
+    restarts: List[int] = []
+    no_improve = 0
+    timed_out = False
+
+    for generation in range(1, config.generations + 1):
+        if generation > 1 and deadline.expired():
+            timed_out = True
+            break
+        population, lengths = _next_generation(population, lengths, config, distance_matrix, rng)
+
+        gen_best_idx = min(range(len(population)), key=lambda idx: lengths[idx])
+        if lengths[gen_best_idx] < best_len:
+            best_len = lengths[gen_best_idx]
+            best_tour = population[gen_best_idx].copy()
+            no_improve = 0
+        else:
+            no_improve += 1
+        history.append(best_len)
+
+        diversity.append(mean_route_distance(population))
+        threshold = config.diversity_restart_threshold
+        if threshold is not None and diversity[-1] < threshold:
+            _restart_population(
+                population, lengths, config.diversity_restart_fraction, distance_matrix, rng
+            )
+            restarts.append(generation)
+
+        if progress is not None:
+            progress(generation, best_len)
+        if config.stagnation_limit is not None and no_improve >= config.stagnation_limit:
+            break
+
+    return best_tour, best_len, history, timed_out, diversity, restarts
+
+
+def genetic_solver(
+    distance_matrix: DistanceMatrix,
+    config: GeneticConfig,
+    result: AlgorithmResult,
+    progress: Optional[ProgressCallback] = None,
+    initial: Optional[Tour] = None,
+) -> Callable[[], AlgorithmResult]:
+    """
+    Zapisuje parametry config w wyniku i zwraca funkcję uruchamiającą algorytm
+    genetyczny, która uzupełnia wynik (trasa, metryki, serie) - wspólną dla
+    run_genetic i polecenia solve, mierzoną przez timed.
+    """
+    for key, value in config.to_parameters().items():
+        result.with_parameter(key, value)
+    result.with_parameter(
+        "heuristic_seeds", str(config.heuristic_seed_count(len(distance_matrix)))
+    )
+
+    def solve() -> AlgorithmResult:
+        best_tour, best_len, history, timed_out, diversity, restarts = genetic_algorithm(
+            distance_matrix, config, progress, initial
+        )
+        result.set_result(best_len, best_tour)
+        result.set_iterations(len(history))
+        stopped_early = not timed_out and len(history) < config.generations
+        result.with_metric("stopped_early", float(stopped_early))
+        result.with_metric("timed_out", float(timed_out))
+        result.with_convergence(history)
+        result.with_metric("best_generation", float(history.index(best_len) + 1))
+        result.with_metric("restarts", float(len(restarts)))
+        result.with_series("diversity", diversity)
+        result.with_series("restart_generations", [float(g) for g in restarts])
+        return result
+
+    return solve
+
+
+def run_genetic(
+    dataset: TspDataset = TspDataset.TSP_48,
+    config: Optional[GeneticConfig] = None,
+    progress: Optional[ProgressCallback] = None,
+    initial: Optional[Tour] = None,
+    initial_source: Optional[str] = None,
+) -> AlgorithmResult:
+    """
+    Uruchamia algorytm genetyczny i zapisuje wyniki.
+
+    Args:
+        dataset: Zbiór danych TSP do przetworzenia
+        config: Parametry algorytmu (domyślnie GeneticConfig()); jeśli config.seed
+                jest None, ziarno jest losowane i zapisywane w wyniku
+        progress: Opcjonalna funkcja wywoływana po każdym pokoleniu
+        initial: Trasa startowa (np. route wcześniejszego wyniku) dołączana do
+                 populacji
+        initial_source: Skąd pochodzi initial (zapisywane w parametrach wyniku)
+
+    Raises:
+        RouteError: Jeśli initial nie jest permutacją miast zbioru danych
+    """
+    config = config or GeneticConfig()
+    if config.seed is None:
+        config = replace(config, seed=random.randrange(2**32))
+    distance_matrix = load_tsp_dataset(dataset)
+
+    result = AlgorithmResult.new(AlgorithmName.GA.value)
+    result.with_dataset(dataset)
+    result.with_content_hash(distance_matrix)
+    solve = genetic_solver(distance_matrix, config, result, progress, initial)
+    if initial is not None:
+        result.with_warm_start(initial, len(distance_matrix), initial_source)
+
+    print("Uruchamianie algorytmu genetycznego...")
+
+    timed(solve)
+
+    print("Algorytm genetyczny zakończony.")
+
+    monitor = ResultMonitor()
+    filename = monitor.save_result(result)
+    print(f"Wyniki zapisane do: {filename}")
+    print(f"Najlepsza długość trasy: {result.route_length:.2f}")
+    print(f"Czas wykonania: {result.execution_time_s:.3f} s")
+
+    return result
+
+
+if __name__ == "__main__":
+    for dataset in (TspDataset.TSP_48, TspDataset.TSP_76, TspDataset.TSP_127):
+        run_genetic(dataset, GeneticConfig(seed=42))
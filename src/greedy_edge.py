@@ -0,0 +1,132 @@
+from typing import Callable, List, Tuple
+
+from utils.data_types import AlgorithmName, DistanceMatrix, Tour
+from utils.tour import tour_length
+from utils.data_loader import load_tsp_dataset, TspDataset
+from utils.monitoring import AlgorithmResult, ResultMonitor
+from utils.timing import timed
+
+
+def _find(parent: List[int], x: int) -> int:
+    """Zwraca reprezentanta zbioru (union-find z kompresją ścieżek)."""
+    while parent[x] != x:
+        parent[x] = parent[parent[x]]
+        x = parent[x]
+    return x
+
+
+def greedy_edge(
+    distance_matrix: DistanceMatrix, closed: bool = True
+) -> Tuple[Tour, float, int]:
+    """
+    Heurystyka zachłannego wyboru krawędzi:
+    - krawędzie sortowane są rosnąco wg długości
+    - krawędź jest dodawana, jeśli żaden z jej końców nie ma już stopnia 2
+      i nie zamyka przedwcześnie cyklu
+    - po zebraniu n-1 krawędzi ścieżka Hamiltona zamykana jest w cykl.
+
+    Przy closed=False miasto 0 może mieć co najwyżej jedną krawędź, więc jest końcem
+    ścieżki Hamiltona - zwracana jest otwarta ścieżka z miasta 0 (bez powrotu).
+
+    Zwraca (trasa, długość, liczba_rozpatrzonych_krawędzi).
+    """
+    n = len(distance_matrix)
+    if n <= 1:
+        return list(range(n)), 0.0, 0
+
+    edges = sorted(
+        (distance_matrix[i][j], i, j) for i in range(n - 1) for j in range(i + 1, n)
+    )
+
+    degree = [0] * n
+    parent = list(range(n))
+    adjacency: List[List[int]] = [[] for _ in range(n)]
+    added = 0
+    considered = 0
+
+    for _, i, j in edges:
+        if added == n - 1:
+            break
+        considered += 1
+        if degree[i] >= 2 or degree[j] >= 2:
+            continue
+        if not closed and (i == 0 or j == 0) and degree[0] >= 1:
+            continue
+        root_i, root_j = _find(parent, i), _find(parent, j)
+        if root_i == root_j:
+            continue
+        parent[root_i] = root_j
+        degree[i] += 1
+        degree[j] += 1
+        adjacency[i].append(j)
+        adjacency[j].append(i)
+        added += 1
+
+    # Przejście po ścieżce Hamiltona od jednego z jej końców
+    start = 0 if not closed else next(city for city in range(n) if degree[city] < 2)
+    tour = [start]
+    previous, current = -1, start
+    while len(tour) < n:
+        next_city = next(c for c in adjacency[current] if c != previous)
+        tour.append(next_city)
+        previous, current = current, next_city
+
+    return tour, tour_length(tour, distance_matrix, closed=closed), considered
+
+
+def greedy_edge_solver(
+    distance_matrix: DistanceMatrix, result: AlgorithmResult, closed: bool = True
+) -> Callable[[], AlgorithmResult]:
+    """
+    Zwraca funkcję budującą trasę zachłannego wyboru krawędzi i uzupełniającą wynik
+    (dla closed=False wynik jest od razu oznaczany jako trasa otwarta); używana przez
+    run_greedy_edge i polecenie solve.
+    """
+    if not closed:
+        result.with_open_tour()
+
+    def solve() -> AlgorithmResult:
+        best_tour, best_len, considered = greedy_edge(distance_matrix, closed)
+        result.set_result(best_len, best_tour)
+        result.set_iterations(considered)
+        result.with_metric("edges_considered", float(considered))
+        return result
+
+    return solve
+
+
+def run_greedy_edge(
+    dataset: TspDataset = TspDataset.TSP_48, closed: bool = True
+) -> AlgorithmResult:
+    """
+    Uruchamia heurystykę zachłannego wyboru krawędzi i zapisuje wyniki.
+
+    Args:
+        dataset: Zbiór danych TSP do przetworzenia
+        closed: Czy trasa wraca do miasta startowego (False - otwarta ścieżka z miasta 0)
+    """
+    distance_matrix = load_tsp_dataset(dataset)
+
+    result = AlgorithmResult.new(AlgorithmName.GREEDY_EDGE.value)
+    result.with_dataset(dataset)
+    result.with_content_hash(distance_matrix)
+    solve = greedy_edge_solver(distance_matrix, result, closed)
+
+    print("Uruchamianie heurystyki zachłannego wyboru krawędzi...")
+
+    timed(solve)
+
+    print("Heurystyka zachłannego wyboru krawędzi zakończona.")
+
+    monitor = ResultMonitor()
+    filename = monitor.save_result(result)
+    print(f"Wyniki zapisane do: {filename}")
+    print(f"Najlepsza długość trasy: {result.route_length:.2f}")
+    print(f"Czas wykonania: {result.execution_time_s:.3f} s")
+
+    return result
+
+
+if __name__ == "__main__":
+    for dataset in (TspDataset.TSP_48, TspDataset.TSP_76, TspDataset.TSP_127):
+        run_greedy_edge(dataset)
@@ -1,19 +1,13 @@
 import random
-from typing import Tuple
+from typing import Callable, List, Optional, Tuple
 
-from utils.data_types import AlgorithmName, DistanceMatrix, Tour
-from utils.tour import tour_length
+from utils.data_types import AlgorithmName, DistanceMatrix, ProgressCallback, Tour
+from utils.tour import random_tour, tour_length
 from utils.data_loader import load_tsp_dataset, TspDataset
 from utils.monitoring import AlgorithmResult, ResultMonitor
-from utils.timing import measure_execution_time
+from utils.timing import Deadline, timed
 
 
-def random_tour(n: int) -> Tour:
-    """Generuje losową trasę (permutację miast od 0 do n-1)."""
-    tour = list(range(n))
-    random.shuffle(tour)
-    return tour
-
 def best_improving_neighbor_swap(
     tour: Tour, distance_matrix: DistanceMatrix
 ) -> Tuple[Tour, float]:
@@ -63,60 +57,132 @@ def hill_climbing(
 def iterative_hill_climbing(
     distance_matrix: DistanceMatrix,
     num_starts: int = 50,
-) -> Tuple[Tour, float]:
+    seed: Optional[int] = None,
+    progress: Optional[ProgressCallback] = None,
+    max_duration_s: Optional[float] = None,
+    start_tour: Optional[Tour] = None,
+) -> Tuple[Tour, float, List[float], bool]:
     """
     Iteracyjna wspinaczka (multistart):
-    - num_starts losowych startów
+    - num_starts losowych startów (powtarzalnych dla tego samego seed)
     - dla każdego uruchamiamy hill_climbing
-    - zwracamy najlepszy wynik.
+    - zwracamy najlepszy wynik, najlepszą dotychczasową długość po każdym starcie
+      i informację, czy przerwano po przekroczeniu max_duration_s (sprawdzane przed
+      każdym kolejnym startem; liczba wykonanych startów to długość historii).
+    Jeśli podano progress, jest wywoływany po każdym starcie z (start, najlepsza_długość).
+    Jeśli podano start_tour, pierwszy start zaczyna się od niej zamiast od losowej trasy.
     """
+    if max_duration_s is not None and max_duration_s <= 0:
+        raise ValueError("max_duration_s must be > 0 when provided")
+    deadline = Deadline(max_duration_s)
+
     n = len(distance_matrix)
+    rng = random.Random(seed)
     best_tour_overall = None
     best_len_overall = float("inf")
+    history: List[float] = []
+    timed_out = False
 
-    for _ in range(num_starts):
-        start = random_tour(n)
+    for start_no in range(1, num_starts + 1):
+        if start_no > 1 and deadline.expired():
+            timed_out = True
+            break
+        if start_no == 1 and start_tour is not None:
+            start = start_tour.copy()
+        else:
+            start = random_tour(n, rng)
         local_best_tour, local_best_len = hill_climbing(start, distance_matrix)
 
         if local_best_len < best_len_overall:
             best_len_overall = local_best_len
             best_tour_overall = local_best_tour
+        history.append(best_len_overall)
+        if progress is not None:
+            progress(start_no, best_len_overall)
+
+    return best_tour_overall, best_len_overall, history, timed_out
+
+
+def ihc_solver(
+    distance_matrix: DistanceMatrix,
+    result: AlgorithmResult,
+    num_starts: int = 50,
+    seed: Optional[int] = None,
+    progress: Optional[ProgressCallback] = None,
+    max_duration_s: Optional[float] = None,
+    initial: Optional[Tour] = None,
+) -> Callable[[], AlgorithmResult]:
+    """
+    Zapisuje num_starts, seed i max_duration_s w parametrach wyniku i zwraca funkcję
+    uruchamiającą wspinaczkę z multistartem; używana przez run_ihc i polecenie solve.
+    """
+    result.with_parameter("num_starts", str(num_starts))
+    result.with_parameter("seed", str(seed))
+    result.with_parameter("max_duration_s", str(max_duration_s))
+
+    def solve() -> AlgorithmResult:
+        best_tour, best_len, history, timed_out = iterative_hill_climbing(
+            distance_matrix, num_starts, seed, progress, max_duration_s, initial
+        )
+        result.set_result(best_len, best_tour)
+        result.set_iterations(len(history))
+        result.with_metric("timed_out", float(timed_out))
+        result.with_convergence(history)
+        return result
 
-    return best_tour_overall, best_len_overall
+    return solve
 
 
-def run_ihc(dataset: TspDataset = TspDataset.TSP_48, num_starts: int = 50) -> AlgorithmResult:
+def run_ihc(
+    dataset: TspDataset = TspDataset.TSP_48,
+    num_starts: int = 50,
+    seed: Optional[int] = None,
+    progress: Optional[ProgressCallback] = None,
+    max_duration_s: Optional[float] = None,
+    initial: Optional[Tour] = None,
+    initial_source: Optional[str] = None,
+) -> AlgorithmResult:
     """
     Uruchamia algorytm wspinaczki z multistartem i zapisuje wyniki.
     
     Args:
         dataset: Zbiór danych TSP do przetworzenia
         num_starts: Liczba losowych startów dla algorytmu
+        seed: Ziarno generatora losowego (None - losowane i zapisywane w wyniku)
+        progress: Opcjonalna funkcja wywoływana po każdym starcie
+        max_duration_s: Limit czasu obliczeń w sekundach (None - brak)
+        initial: Trasa startowa pierwszego startu (np. route wcześniejszego wyniku)
+        initial_source: Skąd pochodzi initial (zapisywane w parametrach wyniku)
+
+    Raises:
+        RouteError: Jeśli initial nie jest permutacją miast zbioru danych
     """
     distance_matrix = load_tsp_dataset(dataset)
+    if seed is None:
+        seed = random.randrange(2**32)
     
     result = AlgorithmResult.new(AlgorithmName.IHC.value)
     result.with_dataset(dataset)
-    result.with_parameter("num_starts", str(num_starts))
+    result.with_content_hash(distance_matrix)
+    solve = ihc_solver(
+        distance_matrix, result, num_starts, seed, progress, max_duration_s, initial
+    )
+    if initial is not None:
+        result.with_warm_start(initial, len(distance_matrix), initial_source)
     
     print("Uruchamianie algorytmu wspinaczki z multistartem...")
 
-    (best_tour, best_len), execution_time_s = measure_execution_time(
-        lambda: iterative_hill_climbing(distance_matrix, num_starts)
-    )
+    timed(solve)
 
     print("Algorytm wspinaczki z multistartem zakończony.")
     
-    result.set_result(best_len, best_tour)
-    result.set_execution_time(execution_time_s)
-    result.set_iterations(num_starts)
-    
     monitor = ResultMonitor()
     filename = monitor.save_result(result)
     print(f"Wyniki zapisane do: {filename}")
-    print(f"Najlepsza długość trasy: {best_len:.2f}")
-    print(f"Czas wykonania: {execution_time_s:.3f} s")
+    print(f"Najlepsza długość trasy: {result.route_length:.2f}")
+    print(f"Czas wykonania: {result.execution_time_s:.3f} s")
 
+    return result
 
 
 if __name__ == "__main__":
@@ -0,0 +1,186 @@
+import random
+from dataclasses import dataclass, replace
+from typing import Callable, Dict, List, Optional, Tuple
+
+from utils.data_types import AlgorithmName, DistanceMatrix, ProgressCallback, Tour
+from utils.tour import double_bridge, random_tour, tour_length
+from utils.data_loader import load_tsp_dataset, TspDataset
+from utils.local_search import two_opt
+from utils.monitoring import AlgorithmResult, ResultMonitor
+from utils.timing import Deadline, timed
+
+
+@dataclass
+class IlsConfig:
+    """Parametry Iterated Local Search."""
+
+    # Liczba perturbacji (każda zakończona przeszukiwaniem 2-opt)
+    iterations: int = 200
+    # Ograniczenie ruchów 2-opt do list najbliższych sąsiadów (None - pełne sąsiedztwo)
+    neighbors: Optional[int] = None
+    # Zatrzymanie po tylu iteracjach bez poprawy najlepszej trasy (None - brak)
+    stagnation_limit: Optional[int] = None
+    # Limit czasu obliczeń w sekundach (None - brak)
+    max_duration_s: Optional[float] = None
+    seed: Optional[int] = None
+
+    def validate(self) -> None:
+        """Sprawdza poprawność parametrów."""
+        if self.iterations <= 0:
+            raise ValueError("iterations must be > 0")
+        if self.neighbors is not None and self.neighbors < 1:
+            raise ValueError("neighbors must be >= 1 when provided")
+        if self.stagnation_limit is not None and self.stagnation_limit <= 0:
+            raise ValueError("stagnation_limit must be > 0 when provided")
+        if self.max_duration_s is not None and self.max_duration_s <= 0:
+            raise ValueError("max_duration_s must be > 0 when provided")
+
+    def to_parameters(self) -> Dict[str, str]:
+        """Zwraca parametry w postaci do zapisania w AlgorithmResult."""
+        return {
+            "iterations": str(self.iterations),
+            "neighbors": str(self.neighbors),
+            "stagnation_limit": str(self.stagnation_limit),
+            "max_duration_s": str(self.max_duration_s),
+            "seed": str(self.seed),
+        }
+
+
+def iterated_local_search(
+    distance_matrix: DistanceMatrix,
+    config: IlsConfig,
+    progress: Optional[ProgressCallback] = None,
+    start_tour: Optional[Tour] = None,
+) -> Tuple[Tour, float, int, List[float], bool, bool]:
+    """
+    Iterated Local Search dla TSP:
+    - losowa trasa startowa (lub start_tour, jeśli podano) poprawiona przez 2-opt
+    - w każdej iteracji perturbacja double-bridge najlepszej trasy i ponowny 2-opt
+    - nowa trasa zastępuje najlepszą tylko wtedy, gdy jest krótsza
+
+    Zwraca (najlepsza_trasa, długość, liczba_popraw,
+    najlepsza_dotychczasowa_długość_po_każdej_iteracji, zatrzymano_przez_stagnację,
+    przekroczono_limit_czasu). Liczba wykonanych iteracji to długość historii.
+    Jeśli podano progress, jest wywoływany po każdej iteracji z (iteracja, najlepsza_długość).
+    """
+    config.validate()
+    deadline = Deadline(config.max_duration_s)
+
+    n = len(distance_matrix)
+    rng = random.Random(config.seed)
+
+    best_tour = start_tour.copy() if start_tour is not None else random_tour(n, rng)
+    two_opt(best_tour, distance_matrix, config.neighbors)
+    best_len = tour_length(best_tour, distance_matrix)
+
+    improvements = 0
+    history: List[float] = []
+    no_improve = 0
+    stopped_early = False
+    timed_out = False
+
+    for iteration in range(1, config.iterations + 1):
+        if iteration > 1 and deadline.expired():
+            timed_out = True
+            break
+
+        candidate = double_bridge(best_tour, rng)
+        two_opt(candidate, distance_matrix, config.neighbors)
+        candidate_len = tour_length(candidate, distance_matrix)
+
+        if candidate_len < best_len:
+            best_tour, best_len = candidate, candidate_len
+            improvements += 1
+            no_improve = 0
+        else:
+            no_improve += 1
+        history.append(best_len)
+        if progress is not None:
+            progress(iteration, best_len)
+        if config.stagnation_limit is not None and no_improve >= config.stagnation_limit:
+            stopped_early = True
+            break
+
+    return best_tour, best_len, improvements, history, stopped_early, timed_out
+
+
+def ils_solver(
+    distance_matrix: DistanceMatrix,
+    config: IlsConfig,
+    result: AlgorithmResult,
+    progress: Optional[ProgressCallback] = None,
+    initial: Optional[Tour] = None,
+) -> Callable[[], AlgorithmResult]:
+    """
+    Zapisuje parametry config w wyniku i zwraca funkcję wykonującą Iterated Local
+    Search; używana przez run_ils i polecenie solve.
+    """
+    for key, value in config.to_parameters().items():
+        result.with_parameter(key, value)
+
+    def solve() -> AlgorithmResult:
+        (
+            best_tour, best_len, improvements, history, stopped_early, timed_out
+        ) = iterated_local_search(distance_matrix, config, progress, initial)
+        result.set_result(best_len, best_tour)
+        result.set_iterations(len(history))
+        result.with_convergence(history)
+        result.with_metric("improvements", float(improvements))
+        result.with_metric("stopped_early", float(stopped_early))
+        result.with_metric("timed_out", float(timed_out))
+        return result
+
+    return solve
+
+
+def run_ils(
+    dataset: TspDataset = TspDataset.TSP_48,
+    config: Optional[IlsConfig] = None,
+    progress: Optional[ProgressCallback] = None,
+    initial: Optional[Tour] = None,
+    initial_source: Optional[str] = None,
+) -> AlgorithmResult:
+    """
+    Uruchamia Iterated Local Search i zapisuje wyniki.
+
+    Args:
+        dataset: Zbiór danych TSP do przetworzenia
+        config: Parametry algorytmu (domyślnie IlsConfig()); jeśli config.seed
+                jest None, ziarno jest losowane i zapisywane w wyniku
+        progress: Opcjonalna funkcja wywoływana po każdej iteracji
+        initial: Trasa startowa (np. route wcześniejszego wyniku) zamiast losowej
+        initial_source: Skąd pochodzi initial (zapisywane w parametrach wyniku)
+
+    Raises:
+        RouteError: Jeśli initial nie jest permutacją miast zbioru danych
+    """
+    config = config or IlsConfig()
+    if config.seed is None:
+        config = replace(config, seed=random.randrange(2**32))
+    distance_matrix = load_tsp_dataset(dataset)
+
+    result = AlgorithmResult.new(AlgorithmName.ILS.value)
+    result.with_dataset(dataset)
+    result.with_content_hash(distance_matrix)
+    solve = ils_solver(distance_matrix, config, result, progress, initial)
+    if initial is not None:
+        result.with_warm_start(initial, len(distance_matrix), initial_source)
+
+    print("Uruchamianie Iterated Local Search...")
+
+    timed(solve)
+
+    print("Iterated Local Search zakończone.")
+
+    monitor = ResultMonitor()
+    filename = monitor.save_result(result)
+    print(f"Wyniki zapisane do: {filename}")
+    print(f"Najlepsza długość trasy: {result.route_length:.2f}")
+    print(f"Czas wykonania: {result.execution_time_s:.3f} s")
+
+    return result
+
+
+if __name__ == "__main__":
+    for dataset in (TspDataset.TSP_48, TspDataset.TSP_76, TspDataset.TSP_127):
+        run_ils(dataset, IlsConfig(seed=42))
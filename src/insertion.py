@@ -0,0 +1,201 @@
+from typing import Callable, List, Tuple
+
+from utils.data_types import AlgorithmName, DistanceMatrix, Tour
+from utils.tour import tour_length
+from utils.data_loader import load_tsp_dataset, TspDataset
+from utils.monitoring import AlgorithmResult, ResultMonitor
+from utils.timing import timed
+
+
+def _cheapest_position(
+    tour: Tour, distance_matrix: DistanceMatrix, city: int, closed: bool = True
+) -> Tuple[int, float]:
+    """
+    Zwraca (pozycja, koszt) najtańszego wstawienia miasta do zamkniętej trasy:
+    miasto trafia między tour[pos - 1] i tour[pos], koszt to d(a, city) + d(city, b) - d(a, b).
+
+    Dla otwartej ścieżki (closed=False) miasto startowe tour[0] pozostaje na początku,
+    a wstawienie na koniec (pos = len(tour)) kosztuje d(tour[-1], city).
+    """
+    best_pos, best_cost = 0, float("inf")
+    positions = range(len(tour)) if closed else range(1, len(tour) + 1)
+    for pos in positions:
+        if pos == len(tour):
+            cost = distance_matrix[tour[-1]][city]
+        else:
+            a, b = tour[pos - 1], tour[pos]
+            cost = distance_matrix[a][city] + distance_matrix[city][b] - distance_matrix[a][b]
+        if cost < best_cost:
+            best_pos, best_cost = pos, cost
+    return best_pos, best_cost
+
+
+def _initial_pair(distance_matrix: DistanceMatrix, farthest: bool) -> Tour:
+    """Trasa startowa: miasto 0 i najbliższe (lub najdalsze) od niego miasto."""
+    n = len(distance_matrix)
+    others = range(1, n)
+    if farthest:
+        second = max(others, key=lambda city: distance_matrix[0][city])
+    else:
+        second = min(others, key=lambda city: distance_matrix[0][city])
+    return [0, second]
+
+
+def cheapest_insertion(
+    distance_matrix: DistanceMatrix, closed: bool = True
+) -> Tuple[Tour, float, int]:
+    """
+    Heurystyka najtańszego wstawiania:
+    - start od miasta 0 i jego najbliższego sąsiada
+    - w każdym kroku wstawiane jest to nieodwiedzone miasto (w to miejsce trasy),
+      które najmniej wydłuża trasę.
+
+    Przy closed=False budowana jest otwarta ścieżka z miasta 0 (bez powrotu).
+
+    Zwraca (trasa, długość, liczba_wstawień).
+    """
+    n = len(distance_matrix)
+    if n <= 2:
+        tour = list(range(n))
+        return tour, tour_length(tour, distance_matrix, closed=closed), 0
+
+    tour = _initial_pair(distance_matrix, farthest=False)
+    unrouted = set(range(n)) - set(tour)
+    insertions = 0
+
+    while unrouted:
+        best_city, best_pos, best_cost = -1, 0, float("inf")
+        for city in sorted(unrouted):
+            pos, cost = _cheapest_position(tour, distance_matrix, city, closed)
+            if cost < best_cost:
+                best_city, best_pos, best_cost = city, pos, cost
+        tour.insert(best_pos, best_city)
+        unrouted.remove(best_city)
+        insertions += 1
+
+    return tour, tour_length(tour, distance_matrix, closed=closed), insertions
+
+
+def farthest_insertion(
+    distance_matrix: DistanceMatrix, closed: bool = True
+) -> Tuple[Tour, float, int]:
+    """
+    Heurystyka najdalszego wstawiania:
+    - start od miasta 0 i najdalszego od niego miasta
+    - w każdym kroku wybierane jest nieodwiedzone miasto najdalsze od trasy
+      (o największej odległości od najbliższego miasta trasy) i wstawiane
+      w miejscu najmniej wydłużającym trasę.
+
+    Przy closed=False budowana jest otwarta ścieżka z miasta 0 (bez powrotu).
+
+    Zwraca (trasa, długość, liczba_wstawień).
+    """
+    n = len(distance_matrix)
+    if n <= 2:
+        tour = list(range(n))
+        return tour, tour_length(tour, distance_matrix, closed=closed), 0
+
+    tour = _initial_pair(distance_matrix, farthest=True)
+    # Odległość każdego miasta od najbliższego miasta trasy
+    distance_to_tour: List[float] = [
+        min(distance_matrix[city][t] for t in tour) for city in range(n)
+    ]
+    unrouted = set(range(n)) - set(tour)
+    insertions = 0
+
+    while unrouted:
+        city = max(sorted(unrouted), key=lambda c: distance_to_tour[c])
+        pos, _ = _cheapest_position(tour, distance_matrix, city, closed)
+        tour.insert(pos, city)
+        unrouted.remove(city)
+        insertions += 1
+        for other in unrouted:
+            distance_to_tour[other] = min(distance_to_tour[other], distance_matrix[other][city])
+
+    return tour, tour_length(tour, distance_matrix, closed=closed), insertions
+
+
+def insertion_solver(
+    distance_matrix: DistanceMatrix,
+    algorithm: AlgorithmName,
+    result: AlgorithmResult,
+    closed: bool = True,
+) -> Callable[[], AlgorithmResult]:
+    """
+    Zwraca funkcję budującą trasę heurystyką wstawiania (CHEAPEST_INSERTION lub
+    FARTHEST_INSERTION) i uzupełniającą wynik; używana przez run_cheapest_insertion,
+    run_farthest_insertion i polecenie solve.
+    """
+    if algorithm == AlgorithmName.CHEAPEST_INSERTION:
+        construct = cheapest_insertion
+    else:
+        construct = farthest_insertion
+    if not closed:
+        result.with_open_tour()
+
+    def solve() -> AlgorithmResult:
+        best_tour, best_len, insertions = construct(distance_matrix, closed)
+        result.set_result(best_len, best_tour)
+        result.set_iterations(insertions)
+        result.with_metric("insertions", float(insertions))
+        return result
+
+    return solve
+
+
+def _run_insertion(
+    dataset: TspDataset, algorithm: AlgorithmName, closed: bool = True
+) -> AlgorithmResult:
+    distance_matrix = load_tsp_dataset(dataset)
+    label = "najtańszego" if algorithm == AlgorithmName.CHEAPEST_INSERTION else "najdalszego"
+
+    result = AlgorithmResult.new(algorithm.value)
+    result.with_dataset(dataset)
+    result.with_content_hash(distance_matrix)
+    solve = insertion_solver(distance_matrix, algorithm, result, closed)
+
+    print(f"Uruchamianie heurystyki {label} wstawiania...")
+
+    timed(solve)
+
+    print(f"Heurystyka {label} wstawiania zakończona.")
+
+    monitor = ResultMonitor()
+    filename = monitor.save_result(result)
+    print(f"Wyniki zapisane do: {filename}")
+    print(f"Najlepsza długość trasy: {result.route_length:.2f}")
+    print(f"Czas wykonania: {result.execution_time_s:.3f} s")
+
+    return result
+
+
+def run_cheapest_insertion(
+    dataset: TspDataset = TspDataset.TSP_48, closed: bool = True
+) -> AlgorithmResult:
+    """
+    Uruchamia heurystykę najtańszego wstawiania i zapisuje wyniki.
+
+    Args:
+        dataset: Zbiór danych TSP do przetworzenia
+        closed: Czy trasa wraca do miasta startowego (False - otwarta ścieżka z miasta 0)
+    """
+    return _run_insertion(dataset, AlgorithmName.CHEAPEST_INSERTION, closed)
+
+
+def run_farthest_insertion(
+    dataset: TspDataset = TspDataset.TSP_48, closed: bool = True
+) -> AlgorithmResult:
+    """
+    Uruchamia heurystykę najdalszego wstawiania i zapisuje wyniki.
+
+    Args:
+        dataset: Zbiór danych TSP do przetworzenia
+        closed: Czy trasa wraca do miasta startowego (False - otwarta ścieżka z miasta 0)
+    """
+    return _run_insertion(dataset, AlgorithmName.FARTHEST_INSERTION, closed)
+
+
+if __name__ == "__main__":
+    for dataset in (TspDataset.TSP_48, TspDataset.TSP_76, TspDataset.TSP_127):
+        run_cheapest_insertion(dataset)
+        run_farthest_insertion(dataset)
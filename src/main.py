@@ -2,12 +2,250 @@
 Główny punkt wejścia aplikacji dla Systemu Monitorowania Algorytmów TSP.
 """
 
-from utils.data_loader import load_tsp_dataset, TspDataset
+import argparse
+import random
+import sys
+from pathlib import Path
+from typing import Dict, List, Optional
 
+from utils.monitoring import CORRUPT_DIRNAME, AlgorithmResult, ResultMonitor
+from utils.tour import DEFAULT_LENGTH_TOLERANCE
+from solve import SOLVERS, SolveOptions, load_instance_file, solve_instance
 
-def main():
-    pass
 
-if __name__ == "__main__":
-    main()
+def print_result(filename: str, result: AlgorithmResult) -> None:
+    """Wypisuje wynik w czytelnej postaci tabeli."""
+    rows = [
+        ("plik", filename),
+        ("algorytm", result.algorithm_name),
+        ("zbiór danych", result.dataset_name),
+        ("eksperyment", result.experiment_id or "-"),
+        ("tagi", ", ".join(result.tags) or "-"),
+        ("hash instancji", (result.content_hash or "-")[:16]),
+        ("długość trasy", f"{result.route_length:.2f}"),
+        ("iteracje", str(result.iterations)),
+        ("czas wykonania", f"{result.execution_time_s:.3f} s"),
+        ("start", result.start_timestamp.isoformat()),
+    ]
+    rows += [(f"parametr: {k}", v) for k, v in sorted(result.parameters.items())]
+    rows += [(f"metryka: {k}", f"{v:g}") for k, v in sorted(result.additional_metrics.items())]
+
+    width = max(len(label) for label, _ in rows)
+    for label, value in rows:
+        print(f"{label.ljust(width)}  {value}")
+
+
+def cmd_results_list(monitor: ResultMonitor, args: argparse.Namespace) -> int:
+    filenames = monitor.list_results_filtered(algorithm=args.algorithm)
+    for filename in filenames:
+        print(filename)
+    print(f"Liczba wyników: {len(filenames)}")
+    return 0
+
+
+def cmd_results_show(monitor: ResultMonitor, args: argparse.Namespace) -> int:
+    print_result(args.filename, monitor.load_result(args.filename))
+    return 0
+
+
+def cmd_results_report(monitor: ResultMonitor, args: argparse.Namespace) -> int:
+    report = monitor.save_report(monitor.load_result(args.filename), args.filename)
+    print(f"Zapisano raport: {report}")
+    return 0
+
+
+def cmd_results_best(monitor: ResultMonitor, args: argparse.Namespace) -> int:
+    best = monitor.best_result(args.algorithm)
+    if best is None:
+        print("Brak wyników.")
+        return 1
+    print_result("-", best)
+    return 0
+
+
+def cmd_results_diff(monitor: ResultMonitor, args: argparse.Namespace) -> int:
+    first = monitor.load_result(args.first)
+    second = monitor.load_result(args.second)
+    diff = first.diff(second)
+    print(f"różnica długości trasy  {diff.route_length_delta:+.2f}")
+    print(f"różnica czasu wykonania {diff.execution_time_delta_s:+.3f} s")
+    print(f"różnica liczby iteracji {diff.iterations_delta:+d}")
+    for key, (old, new) in diff.parameter_changes.items():
+        print(f"parametr: {key}  {old} -> {new}")
+    for key, (old, new) in diff.metric_changes.items():
+        print(f"metryka: {key}  {old} -> {new}")
+    return 0
+
+
+def cmd_results_watch(monitor: ResultMonitor, args: argparse.Namespace) -> int:
+    best: Dict[str, float] = {}
+
+    def on_result(result: AlgorithmResult) -> None:
+        key = f"{result.algorithm_name} ({result.dataset_name})"
+        best[key] = min(best.get(key, result.route_length), result.route_length)
+        print(
+            f"{result.algorithm_name}: {result.route_length:.2f} "
+            f"(najlepsza dla {result.dataset_name}: {best[key]:.2f})",
+            flush=True,
+        )
+
+    print(f"Obserwowanie katalogu {monitor.results_dir} (Ctrl+C kończy)...")
+    try:
+        monitor.watch(on_result, interval_s=args.interval, include_existing=args.existing)
+    except KeyboardInterrupt:
+        pass
+    return 0
+
+
+def cmd_results_check(monitor: ResultMonitor, args: argparse.Namespace) -> int:
+    broken = monitor.check_integrity(repair=args.repair)
+    for filename, error in broken:
+        print(f"{filename}: {error}")
+    if args.repair and broken:
+        print(f"Przeniesiono do {CORRUPT_DIRNAME}/: {len(broken)}")
+    print(f"Uszkodzonych wyników: {len(broken)}")
+    return 1 if broken else 0
+
+
+def cmd_results_prune(monitor: ResultMonitor, args: argparse.Namespace) -> int:
+    removed = monitor.prune(args.keep)
+    print(f"Usunięto wyników: {removed}")
+    return 0
+
+
+def cmd_solve(monitor: ResultMonitor, args: argparse.Namespace) -> int:
+    distance_matrix, instance_name = load_instance_file(args.instance)
+    seed = args.seed if args.seed is not None else random.randrange(2**32)
+    options = SolveOptions(
+        seed=seed, iterations=args.iterations, max_duration_s=args.time_limit
+    )
 
+    print(f"Uruchamianie {args.algorithm} na {instance_name} ({len(distance_matrix)} miast)...")
+    result = solve_instance(distance_matrix, args.algorithm, options, instance_name)
+    filename = monitor.save_result(result)
+    print_result(filename, result)
+    return 0
+
+
+def cmd_audit(monitor: ResultMonitor, args: argparse.Namespace) -> int:
+    distance_matrix, instance_name = load_instance_file(args.instance)
+    mismatches, audited = monitor.audit_lengths(distance_matrix, args.tolerance)
+    for filename, problem in mismatches:
+        print(f"{filename}: {problem}")
+    print(f"Sprawdzono wyników instancji {instance_name}: {audited}")
+    print(f"Niezgodnych długości tras: {len(mismatches)}")
+    return 1 if mismatches else 0
+
+
+def build_parser() -> argparse.ArgumentParser:
+    """Buduje parser argumentów wiersza poleceń."""
+    parser = argparse.ArgumentParser(description="System Monitorowania Algorytmów TSP")
+    parser.add_argument(
+        "--results-dir",
+        default=None,
+        help="Katalog wyników, względem bieżącego katalogu (domyślnie: wyniki/ projektu)",
+    )
+    commands = parser.add_subparsers(dest="command", required=True)
+
+    results = commands.add_parser("results", help="Przeglądanie zapisanych wyników")
+    results_commands = results.add_subparsers(dest="results_command", required=True)
+
+    list_cmd = results_commands.add_parser("list", help="Lista plików wyników")
+    list_cmd.add_argument("--algorithm", help="Tylko wyniki danego algorytmu")
+    list_cmd.set_defaults(handler=cmd_results_list)
+
+    show_cmd = results_commands.add_parser("show", help="Szczegóły jednego wyniku")
+    show_cmd.add_argument("filename", help="Nazwa pliku wyniku")
+    show_cmd.set_defaults(handler=cmd_results_show)
+
+    report_cmd = results_commands.add_parser(
+        "report", help="Zapis raportu tekstowego (.txt) obok pliku wyniku"
+    )
+    report_cmd.add_argument("filename", help="Nazwa pliku wyniku")
+    report_cmd.set_defaults(handler=cmd_results_report)
+
+    best_cmd = results_commands.add_parser("best", help="Najlepszy zapisany wynik")
+    best_cmd.add_argument("--algorithm", help="Tylko wyniki danego algorytmu")
+    best_cmd.set_defaults(handler=cmd_results_best)
+
+    diff_cmd = results_commands.add_parser("diff", help="Porównanie dwóch wyników")
+    diff_cmd.add_argument("first", help="Nazwa pliku pierwszego wyniku")
+    diff_cmd.add_argument("second", help="Nazwa pliku drugiego wyniku")
+    diff_cmd.set_defaults(handler=cmd_results_diff)
+
+    watch_cmd = results_commands.add_parser("watch", help="Śledzenie nowych wyników na bieżąco")
+    watch_cmd.add_argument(
+        "--interval", type=float, default=1.0, help="Odstęp sprawdzania w sekundach"
+    )
+    watch_cmd.add_argument(
+        "--existing", action="store_true", help="Wypisz również istniejące wyniki"
+    )
+    watch_cmd.set_defaults(handler=cmd_results_watch)
+
+    check_cmd = results_commands.add_parser("check", help="Sprawdzenie, czy wyniki da się wczytać")
+    check_cmd.add_argument(
+        "--repair", action="store_true", help=f"Przenieś uszkodzone pliki do {CORRUPT_DIRNAME}/"
+    )
+    check_cmd.set_defaults(handler=cmd_results_check)
+
+    prune_cmd = results_commands.add_parser(
+        "prune", help="Usunięcie wyników spoza najlepszych K dla każdego algorytmu"
+    )
+    prune_cmd.add_argument("keep", type=int, help="Liczba zachowywanych wyników na algorytm")
+    prune_cmd.set_defaults(handler=cmd_results_prune)
+
+    solve_cmd = commands.add_parser(
+        "solve", help="Uruchomienie algorytmu na instancji z pliku i zapis wyniku"
+    )
+    solve_cmd.add_argument(
+        "--instance", required=True, help="Plik instancji: TSPLIB (.tsp) lub macierz CSV"
+    )
+    solve_cmd.add_argument(
+        "--algorithm", required=True, choices=sorted(SOLVERS), help="Nazwa algorytmu"
+    )
+    solve_cmd.add_argument(
+        "--seed", type=int, help="Ziarno generatora losowego (domyślnie losowane)"
+    )
+    solve_cmd.add_argument(
+        "--iterations",
+        type=int,
+        help="Główna liczba iteracji algorytmu (pokolenia, starty, iteracje...)",
+    )
+    solve_cmd.add_argument(
+        "--time-limit", type=float, help="Limit czasu obliczeń w sekundach"
+    )
+    solve_cmd.set_defaults(handler=cmd_solve)
+
+    audit_cmd = commands.add_parser(
+        "audit", help="Sprawdzenie zapisanych długości tras wyników danej instancji"
+    )
+    audit_cmd.add_argument(
+        "--instance", required=True, help="Plik instancji: TSPLIB (.tsp) lub macierz CSV"
+    )
+    audit_cmd.add_argument(
+        "--tolerance",
+        type=float,
+        default=DEFAULT_LENGTH_TOLERANCE,
+        help=f"Tolerancja względna (domyślnie: {DEFAULT_LENGTH_TOLERANCE:g})",
+    )
+    audit_cmd.set_defaults(handler=cmd_audit)
+
+    return parser
+
+
+def main(argv: Optional[List[str]] = None) -> int:
+    args = build_parser().parse_args(argv)
+    if args.results_dir is None:
+        monitor = ResultMonitor()
+    else:
+        # Jak --instance: ścieżka względem bieżącego katalogu, nie katalogu projektu
+        monitor = ResultMonitor.with_dir(str(Path(args.results_dir).resolve()))
+    try:
+        return args.handler(monitor, args)
+    except (FileNotFoundError, ValueError) as e:
+        print(f"Błąd: {e}", file=sys.stderr)
+        return 1
+
+
+if __name__ == "__main__":
+    sys.exit(main())
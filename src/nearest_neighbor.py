@@ -0,0 +1,108 @@
+from typing import Callable, Tuple
+
+from utils.data_types import AlgorithmName, DistanceMatrix, Tour
+from utils.data_loader import load_tsp_dataset, TspDataset
+from utils.monitoring import AlgorithmResult, ResultMonitor
+from utils.timing import timed
+
+
+def nearest_neighbor(
+    distance_matrix: DistanceMatrix, start_city: int = 0, closed: bool = True
+) -> Tuple[Tour, float]:
+    """
+    Algorytm najbliższego sąsiada: startuje z 'start_city' i zawsze przechodzi
+    do najbliższego jeszcze nieodwiedzonego miasta.
+    Zwraca (trasa, długość) - długość obejmuje powrót do miasta startowego,
+    chyba że closed=False (otwarta ścieżka z 'start_city').
+    Dla pustej instancji zwraca ([], 0.0).
+    """
+    n = len(distance_matrix)
+    if n == 0:
+        return [], 0.0
+    if not 0 <= start_city < n:
+        raise ValueError(f"start_city must be in range 0..{n - 1}, got {start_city}")
+
+    tour = [start_city]
+    visited = [False] * n
+    visited[start_city] = True
+    length = 0.0
+    current = start_city
+
+    for _ in range(n - 1):
+        next_city = -1
+        next_dist = float("inf")
+        for city in range(n):
+            if not visited[city] and distance_matrix[current][city] < next_dist:
+                next_dist = distance_matrix[current][city]
+                next_city = city
+
+        tour.append(next_city)
+        visited[next_city] = True
+        length += next_dist
+        current = next_city
+
+    if closed:
+        length += distance_matrix[current][start_city]
+    return tour, length
+
+
+def nearest_neighbor_solver(
+    distance_matrix: DistanceMatrix,
+    result: AlgorithmResult,
+    start_city: int = 0,
+    closed: bool = True,
+) -> Callable[[], AlgorithmResult]:
+    """
+    Zapisuje start_city w parametrach wyniku (i oznacza trasę jako otwartą, jeśli
+    closed jest False) oraz zwraca funkcję budującą trasę najbliższego sąsiada;
+    używana przez run_nearest_neighbor i polecenie solve.
+    """
+    result.with_parameter("start_city", str(start_city))
+    if not closed:
+        result.with_open_tour()
+
+    def solve() -> AlgorithmResult:
+        best_tour, best_len = nearest_neighbor(distance_matrix, start_city, closed)
+        result.set_result(best_len, best_tour)
+        result.set_iterations(len(best_tour))
+        return result
+
+    return solve
+
+
+def run_nearest_neighbor(
+    dataset: TspDataset = TspDataset.TSP_48, start_city: int = 0, closed: bool = True
+) -> AlgorithmResult:
+    """
+    Uruchamia algorytm najbliższego sąsiada i zapisuje wyniki.
+
+    Args:
+        dataset: Zbiór danych TSP do przetworzenia
+        start_city: Miasto startowe trasy
+        closed: Czy trasa wraca do miasta startowego (False - otwarta ścieżka)
+    """
+    distance_matrix = load_tsp_dataset(dataset)
+
+    result = AlgorithmResult.new(AlgorithmName.NN.value)
+    result.with_dataset(dataset)
+    result.with_content_hash(distance_matrix)
+    solve = nearest_neighbor_solver(distance_matrix, result, start_city, closed)
+
+    print("Uruchamianie algorytmu najbliższego sąsiada...")
+
+    timed(solve)
+
+    print("Algorytm najbliższego sąsiada zakończony.")
+
+    monitor = ResultMonitor()
+    filename = monitor.save_result(result)
+    print(f"Wyniki zapisane do: {filename}")
+    print(f"Najlepsza długość trasy: {result.route_length:.2f}")
+    print(f"Czas wykonania: {result.execution_time_s:.3f} s")
+
+    return result
+
+
+if __name__ == "__main__":
+    for dataset in (TspDataset.TSP_48, TspDataset.TSP_76, TspDataset.TSP_127):
+        run_nearest_neighbor(dataset)
@@ -0,0 +1,70 @@
+import random
+from typing import Callable, Optional
+
+from utils.data_types import AlgorithmName, DistanceMatrix
+from utils.tour import random_tour, tour_length
+from utils.data_loader import load_tsp_dataset, TspDataset
+from utils.monitoring import AlgorithmResult, ResultMonitor
+from utils.timing import timed
+
+
+def random_solver(
+    distance_matrix: DistanceMatrix, seed: int, result: AlgorithmResult
+) -> Callable[[], AlgorithmResult]:
+    """
+    Zapisuje ziarno w parametrach wyniku i zwraca funkcję losującą trasę
+    random.Random(seed); używana przez random_tour_result i polecenie solve.
+    """
+    result.with_parameter("seed", str(seed))
+
+    def solve() -> AlgorithmResult:
+        tour = random_tour(len(distance_matrix), random.Random(seed))
+        result.set_result(tour_length(tour, distance_matrix), tour)
+        result.set_iterations(1)
+        return result
+
+    return solve
+
+
+def random_tour_result(distance_matrix: DistanceMatrix, seed: int) -> AlgorithmResult:
+    """
+    Losowa trasa jako punkt odniesienia: permutacja miast z random.Random(seed).
+    Dla tego samego ziarna i rozmiaru zwracana jest ta sama trasa.
+
+    Returns:
+        AlgorithmResult: Wynik z trasą, jej długością i zapisanym ziarnem
+    """
+    result = AlgorithmResult.new(AlgorithmName.RANDOM.value)
+    result.with_num_cities(len(distance_matrix))
+    return timed(random_solver(distance_matrix, seed, result))
+
+
+def run_random(
+    dataset: TspDataset = TspDataset.TSP_48, seed: Optional[int] = None
+) -> AlgorithmResult:
+    """
+    Generuje losową trasę (punkt odniesienia dla pozostałych algorytmów) i zapisuje wynik.
+
+    Args:
+        dataset: Zbiór danych TSP do przetworzenia
+        seed: Ziarno generatora losowego (None - losowane i zapisywane w wyniku)
+    """
+    distance_matrix = load_tsp_dataset(dataset)
+    if seed is None:
+        seed = random.randrange(2**32)
+
+    result = random_tour_result(distance_matrix, seed)
+    result.with_dataset(dataset)
+    result.with_content_hash(distance_matrix)
+
+    monitor = ResultMonitor()
+    filename = monitor.save_result(result)
+    print(f"Wyniki zapisane do: {filename}")
+    print(f"Długość losowej trasy: {result.route_length:.2f}")
+
+    return result
+
+
+if __name__ == "__main__":
+    for dataset in (TspDataset.TSP_48, TspDataset.TSP_76, TspDataset.TSP_127):
+        run_random(dataset)
@@ -0,0 +1,241 @@
+import math
+import random
+from dataclasses import dataclass, replace
+from enum import Enum
+from typing import Callable, Dict, List, Optional, Tuple
+
+from utils.data_types import AlgorithmName, DistanceMatrix, ProgressCallback, Tour
+from utils.tour import random_tour, tour_length
+from utils.data_loader import load_tsp_dataset, TspDataset
+from utils.local_search import delta_two_opt, require_symmetric
+from utils.monitoring import AlgorithmResult, ResultMonitor
+from utils.timing import Deadline, timed
+
+
+class CoolingSchedule(Enum):
+    """Sposób obniżania temperatury."""
+
+    # T <- T * cooling_factor
+    GEOMETRIC = "geometric"
+    # T <- T - cooling_factor
+    LINEAR = "linear"
+
+
+@dataclass
+class SaConfig:
+    """Parametry symulowanego wyżarzania."""
+
+    initial_temperature: float = 1000.0
+    final_temperature: float = 1e-3
+    cooling_factor: float = 0.995
+    iterations_per_temperature: int = 100
+    cooling: CoolingSchedule = CoolingSchedule.GEOMETRIC
+    # Zatrzymanie po tylu temperaturach bez poprawy najlepszej trasy (None - brak)
+    stagnation_limit: Optional[int] = None
+    # Limit czasu obliczeń w sekundach (None - brak)
+    max_duration_s: Optional[float] = None
+    seed: Optional[int] = None
+
+    def validate(self) -> None:
+        """Sprawdza poprawność parametrów (gwarantuje zakończenie pętli chłodzenia)."""
+        if self.final_temperature <= 0:
+            raise ValueError("final_temperature must be > 0")
+        if self.initial_temperature <= self.final_temperature:
+            raise ValueError("initial_temperature must be > final_temperature")
+        if self.iterations_per_temperature <= 0:
+            raise ValueError("iterations_per_temperature must be > 0")
+        if self.cooling == CoolingSchedule.GEOMETRIC and not 0.0 < self.cooling_factor < 1.0:
+            raise ValueError("cooling_factor must be in (0, 1) for geometric cooling")
+        if self.cooling == CoolingSchedule.LINEAR and self.cooling_factor <= 0:
+            raise ValueError("cooling_factor must be > 0 for linear cooling")
+        if self.stagnation_limit is not None and self.stagnation_limit <= 0:
+            raise ValueError("stagnation_limit must be > 0 when provided")
+        if self.max_duration_s is not None and self.max_duration_s <= 0:
+            raise ValueError("max_duration_s must be > 0 when provided")
+
+    def to_parameters(self) -> Dict[str, str]:
+        """Zwraca parametry w postaci do zapisania w AlgorithmResult."""
+        return {
+            "initial_temperature": str(self.initial_temperature),
+            "final_temperature": str(self.final_temperature),
+            "cooling_factor": str(self.cooling_factor),
+            "iterations_per_temperature": str(self.iterations_per_temperature),
+            "cooling": self.cooling.value,
+            "stagnation_limit": str(self.stagnation_limit),
+            "max_duration_s": str(self.max_duration_s),
+            "seed": str(self.seed),
+        }
+
+
+def _cool(temperature: float, config: SaConfig) -> float:
+    """Zwraca kolejną temperaturę; nigdy nie schodzi poniżej final_temperature."""
+    if config.cooling == CoolingSchedule.GEOMETRIC:
+        temperature *= config.cooling_factor
+    else:
+        temperature -= config.cooling_factor
+    return max(temperature, config.final_temperature)
+
+
+def simulated_annealing(
+    distance_matrix: DistanceMatrix,
+    config: SaConfig,
+    progress: Optional[ProgressCallback] = None,
+    start_tour: Optional[Tour] = None,
+) -> Tuple[Tour, float, int, int, int, List[float], bool, bool]:
+    """
+    Symulowane wyżarzanie dla TSP z ruchem 2-opt (odwrócenie fragmentu trasy).
+
+    Gorszy sąsiad akceptowany jest z prawdopodobieństwem exp(-delta / T).
+    Ruch 2-opt zakłada macierz symetryczną.
+    Zwraca (najlepsza_trasa, długość, liczba_temperatur, zaakceptowane, odrzucone,
+    najlepsza_dotychczasowa_długość_po_każdej_temperaturze, zatrzymano_przez_stagnację,
+    przekroczono_limit_czasu). Limit config.max_duration_s sprawdzany jest przed każdą
+    temperaturą.
+    Jeśli podano progress, jest wywoływany po każdej temperaturze z (poziom, najlepsza_długość).
+    Jeśli podano start_tour, wyżarzanie zaczyna się od niej zamiast od losowej trasy.
+    """
+    config.validate()
+    require_symmetric(distance_matrix)
+    deadline = Deadline(config.max_duration_s)
+
+    n = len(distance_matrix)
+    rng = random.Random(config.seed)
+
+    current_tour = start_tour.copy() if start_tour is not None else random_tour(n, rng)
+    current_len = tour_length(current_tour, distance_matrix)
+    best_tour = current_tour.copy()
+    best_len = current_len
+
+    accepted = 0
+    rejected = 0
+    levels = 0
+    history: List[float] = []
+    no_improve = 0
+    stopped_early = False
+    timed_out = False
+    temperature = config.initial_temperature
+
+    while temperature > config.final_temperature and n >= 4:
+        if levels > 0 and deadline.expired():
+            timed_out = True
+            break
+        levels += 1
+        level_start_best = best_len
+        for _ in range(config.iterations_per_temperature):
+            i = rng.randrange(0, n - 1)
+            j = rng.randrange(i + 1, n)
+            if i == 0 and j == n - 1:
+                continue
+
+            delta = delta_two_opt(current_tour, distance_matrix, i, j)
+
+            if delta <= 0 or rng.random() < math.exp(-delta / temperature):
+                current_tour[i : j + 1] = reversed(current_tour[i : j + 1])
+                current_len += delta
+                accepted += 1
+                if current_len < best_len:
+                    best_len = current_len
+                    best_tour = current_tour.copy()
+            else:
+                rejected += 1
+
+        history.append(best_len)
+        if progress is not None:
+            progress(levels, best_len)
+
+        no_improve = 0 if best_len < level_start_best else no_improve + 1
+        if config.stagnation_limit is not None and no_improve >= config.stagnation_limit:
+            stopped_early = True
+            break
+        temperature = _cool(temperature, config)
+
+    # Długość przeliczona od nowa, aby uniknąć kumulacji błędów z sumowania delt
+    best_len = tour_length(best_tour, distance_matrix)
+    return (
+        best_tour, best_len, levels, accepted, rejected, history, stopped_early, timed_out
+    )
+
+
+def simulated_annealing_solver(
+    distance_matrix: DistanceMatrix,
+    config: SaConfig,
+    result: AlgorithmResult,
+    progress: Optional[ProgressCallback] = None,
+    initial: Optional[Tour] = None,
+) -> Callable[[], AlgorithmResult]:
+    """
+    Zapisuje parametry config w wyniku i zwraca funkcję wykonującą wyżarzanie, która
+    uzupełnia trasę, zbieżność i liczniki ruchów; wspólna dla run_simulated_annealing
+    i polecenia solve.
+    """
+    for key, value in config.to_parameters().items():
+        result.with_parameter(key, value)
+
+    def solve() -> AlgorithmResult:
+        (
+            best_tour, best_len, levels, accepted, rejected, history,
+            stopped_early, timed_out,
+        ) = simulated_annealing(distance_matrix, config, progress, initial)
+        result.set_result(best_len, best_tour)
+        result.set_iterations(levels)
+        result.with_convergence(history)
+        result.with_metric("accepted_moves", float(accepted))
+        result.with_metric("rejected_moves", float(rejected))
+        result.with_metric("stopped_early", float(stopped_early))
+        result.with_metric("timed_out", float(timed_out))
+        return result
+
+    return solve
+
+
+def run_simulated_annealing(
+    dataset: TspDataset = TspDataset.TSP_48,
+    config: Optional[SaConfig] = None,
+    progress: Optional[ProgressCallback] = None,
+    initial: Optional[Tour] = None,
+    initial_source: Optional[str] = None,
+) -> AlgorithmResult:
+    """
+    Uruchamia symulowane wyżarzanie i zapisuje wyniki.
+
+    Args:
+        dataset: Zbiór danych TSP do przetworzenia
+        config: Parametry algorytmu (domyślnie SaConfig()); jeśli config.seed
+                jest None, ziarno jest losowane i zapisywane w wyniku
+        progress: Opcjonalna funkcja wywoływana po każdej temperaturze
+        initial: Trasa startowa (np. route wcześniejszego wyniku) zamiast losowej
+        initial_source: Skąd pochodzi initial (zapisywane w parametrach wyniku)
+
+    Raises:
+        RouteError: Jeśli initial nie jest permutacją miast zbioru danych
+    """
+    config = config or SaConfig()
+    if config.seed is None:
+        config = replace(config, seed=random.randrange(2**32))
+    distance_matrix = load_tsp_dataset(dataset)
+
+    result = AlgorithmResult.new(AlgorithmName.SA.value)
+    result.with_dataset(dataset)
+    result.with_content_hash(distance_matrix)
+    solve = simulated_annealing_solver(distance_matrix, config, result, progress, initial)
+    if initial is not None:
+        result.with_warm_start(initial, len(distance_matrix), initial_source)
+
+    print("Uruchamianie symulowanego wyżarzania...")
+
+    timed(solve)
+
+    print("Symulowane wyżarzanie zakończone.")
+
+    monitor = ResultMonitor()
+    filename = monitor.save_result(result)
+    print(f"Wyniki zapisane do: {filename}")
+    print(f"Najlepsza długość trasy: {result.route_length:.2f}")
+    print(f"Czas wykonania: {result.execution_time_s:.3f} s")
+
+    return result
+
+
+if __name__ == "__main__":
+    for dataset in (TspDataset.TSP_48, TspDataset.TSP_76, TspDataset.TSP_127):
+        run_simulated_annealing(dataset)
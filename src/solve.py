@@ -0,0 +1,217 @@
+"""
+Uruchamianie wybranego algorytmu na instancji wczytanej z pliku (polecenie solve w main.py).
+
+Każdy algorytm ma tu adapter, który z ogólnych opcji (ziarno, liczba iteracji, limit
+czasu) buduje konfigurację algorytmu i przekazuje ją do funkcji *_solver modułu
+algorytmu - tej samej, której używa jego run_*, więc parametry i metryki wyniku są
+identyczne. Zwracana funkcja wykonuje obliczenia i jest mierzona przez timed.
+"""
+
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Callable, Dict, Optional, Tuple
+
+from utils.data_loader import load_tsp_data
+from utils.data_types import AlgorithmName, DistanceMatrix
+from utils.monitoring import AlgorithmResult
+from utils.timing import timed
+from utils.tsplib import parse_tsp
+from aco import AcoConfig, aco_solver
+from brute_force import brute_force_solver
+from genetic import GeneticConfig, genetic_solver
+from greedy_edge import greedy_edge_solver
+from ihc import ihc_solver
+from ils import IlsConfig, ils_solver
+from insertion import insertion_solver
+from nearest_neighbor import nearest_neighbor_solver
+from random_baseline import random_solver
+from simulated_annealing import SaConfig, simulated_annealing_solver
+from tabu_search import TabuConfig, tabu_solver
+
+
+@dataclass
+class SolveOptions:
+    """Ogólne opcje uruchomienia algorytmu z wiersza poleceń."""
+
+    seed: int
+    # Główna liczba iteracji algorytmu (None - wartość domyślna algorytmu); znaczenie
+    # zależy od algorytmu (pokolenia, starty, poziomy temperatury...), heurystyki
+    # konstrukcyjne ją pomijają
+    iterations: Optional[int] = None
+    # Limit czasu obliczeń w sekundach (None - brak; tylko algorytmy iteracyjne)
+    max_duration_s: Optional[float] = None
+
+
+# Adapter: (macierz, opcje, wynik) -> funkcja wykonująca obliczenia i uzupełniająca wynik
+Solver = Callable[[DistanceMatrix, SolveOptions, AlgorithmResult], Callable[[], AlgorithmResult]]
+
+
+def _iteration_override(options: SolveOptions, field_name: str) -> dict:
+    """Nadpisanie pola konfiguracji liczbą iteracji z opcji (jeśli podano)."""
+    return {field_name: options.iterations} if options.iterations is not None else {}
+
+
+def _solve_ihc(
+    distance_matrix: DistanceMatrix, options: SolveOptions, result: AlgorithmResult
+) -> Callable[[], AlgorithmResult]:
+    num_starts = options.iterations if options.iterations is not None else 50
+    return ihc_solver(
+        distance_matrix, result, num_starts, options.seed,
+        max_duration_s=options.max_duration_s,
+    )
+
+
+def _solve_tabu(
+    distance_matrix: DistanceMatrix, options: SolveOptions, result: AlgorithmResult
+) -> Callable[[], AlgorithmResult]:
+    config = TabuConfig(
+        seed=options.seed,
+        max_duration_s=options.max_duration_s,
+        **_iteration_override(options, "max_iters"),
+    )
+    return tabu_solver(distance_matrix, config, result)
+
+
+def _solve_simulated_annealing(
+    distance_matrix: DistanceMatrix, options: SolveOptions, result: AlgorithmResult
+) -> Callable[[], AlgorithmResult]:
+    config = SaConfig(
+        seed=options.seed,
+        max_duration_s=options.max_duration_s,
+        **_iteration_override(options, "iterations_per_temperature"),
+    )
+    return simulated_annealing_solver(distance_matrix, config, result)
+
+
+def _solve_genetic(
+    distance_matrix: DistanceMatrix, options: SolveOptions, result: AlgorithmResult
+) -> Callable[[], AlgorithmResult]:
+    config = GeneticConfig(
+        seed=options.seed,
+        max_duration_s=options.max_duration_s,
+        **_iteration_override(options, "generations"),
+    )
+    return genetic_solver(distance_matrix, config, result)
+
+
+def _solve_aco(
+    distance_matrix: DistanceMatrix, options: SolveOptions, result: AlgorithmResult
+) -> Callable[[], AlgorithmResult]:
+    config = AcoConfig(
+        seed=options.seed,
+        max_duration_s=options.max_duration_s,
+        **_iteration_override(options, "iterations"),
+    )
+    return aco_solver(distance_matrix, config, result)
+
+
+def _solve_ils(
+    distance_matrix: DistanceMatrix, options: SolveOptions, result: AlgorithmResult
+) -> Callable[[], AlgorithmResult]:
+    config = IlsConfig(
+        seed=options.seed,
+        max_duration_s=options.max_duration_s,
+        **_iteration_override(options, "iterations"),
+    )
+    return ils_solver(distance_matrix, config, result)
+
+
+def _solve_nearest_neighbor(
+    distance_matrix: DistanceMatrix, options: SolveOptions, result: AlgorithmResult
+) -> Callable[[], AlgorithmResult]:
+    return nearest_neighbor_solver(distance_matrix, result)
+
+
+def _solve_greedy_edge(
+    distance_matrix: DistanceMatrix, options: SolveOptions, result: AlgorithmResult
+) -> Callable[[], AlgorithmResult]:
+    return greedy_edge_solver(distance_matrix, result)
+
+
+def _insertion_solver(algorithm: AlgorithmName) -> Solver:
+    def adapter(
+        distance_matrix: DistanceMatrix, options: SolveOptions, result: AlgorithmResult
+    ) -> Callable[[], AlgorithmResult]:
+        return insertion_solver(distance_matrix, algorithm, result)
+
+    return adapter
+
+
+def _solve_brute_force(
+    distance_matrix: DistanceMatrix, options: SolveOptions, result: AlgorithmResult
+) -> Callable[[], AlgorithmResult]:
+    return brute_force_solver(distance_matrix, result)
+
+
+def _solve_random(
+    distance_matrix: DistanceMatrix, options: SolveOptions, result: AlgorithmResult
+) -> Callable[[], AlgorithmResult]:
+    return random_solver(distance_matrix, options.seed, result)
+
+
+# Adapter dla każdego algorytmu, wg nazwy z AlgorithmName
+SOLVERS: Dict[str, Solver] = {
+    AlgorithmName.IHC.value: _solve_ihc,
+    AlgorithmName.TABU.value: _solve_tabu,
+    AlgorithmName.NN.value: _solve_nearest_neighbor,
+    AlgorithmName.GA.value: _solve_genetic,
+    AlgorithmName.SA.value: _solve_simulated_annealing,
+    AlgorithmName.ACO.value: _solve_aco,
+    AlgorithmName.GREEDY_EDGE.value: _solve_greedy_edge,
+    AlgorithmName.CHEAPEST_INSERTION.value: _insertion_solver(AlgorithmName.CHEAPEST_INSERTION),
+    AlgorithmName.FARTHEST_INSERTION.value: _insertion_solver(AlgorithmName.FARTHEST_INSERTION),
+    AlgorithmName.RANDOM.value: _solve_random,
+    AlgorithmName.ILS.value: _solve_ils,
+    AlgorithmName.BRUTE_FORCE.value: _solve_brute_force,
+}
+
+
+def load_instance_file(path: str) -> Tuple[DistanceMatrix, str]:
+    """
+    Wczytuje instancję z pliku TSPLIB (.tsp) lub macierz odległości z pliku CSV
+    (jak zbiory z katalogu dane/).
+
+    Returns:
+        Tuple[DistanceMatrix, str]: Macierz odległości i nazwa instancji (NAME z pliku
+        TSPLIB lub nazwa pliku CSV bez rozszerzenia)
+
+    Raises:
+        FileNotFoundError: Jeśli plik nie istnieje
+        ValueError: Jeśli pliku nie da się sparsować (TsplibError dla plików .tsp)
+    """
+    file_path = Path(path)
+    if file_path.suffix.lower() == ".tsp":
+        if not file_path.is_file():
+            raise FileNotFoundError(f"Failed to open file: {path}")
+        instance = parse_tsp(file_path)
+        return instance.distance_matrix(), instance.name or file_path.stem
+    return load_tsp_data(path), file_path.stem
+
+
+def solve_instance(
+    distance_matrix: DistanceMatrix,
+    algorithm: str,
+    options: SolveOptions,
+    instance_name: Optional[str] = None,
+) -> AlgorithmResult:
+    """
+    Uruchamia algorytm o podanej nazwie (klucz SOLVERS) na macierzy odległości.
+    Wynik nie jest zapisywany.
+
+    Raises:
+        ValueError: Jeśli algorytm jest nieznany (komunikat zawiera listę dostępnych)
+                    lub opcje są niepoprawne dla danego algorytmu
+    """
+    solver = SOLVERS.get(algorithm)
+    if solver is None:
+        raise ValueError(
+            f"Unknown algorithm {algorithm!r}, available: {', '.join(sorted(SOLVERS))}"
+        )
+
+    result = AlgorithmResult.new(algorithm)
+    result.with_num_cities(len(distance_matrix))
+    if instance_name is not None:
+        result.with_instance(instance_name)
+        result.dataset_name = instance_name
+    result.with_content_hash(distance_matrix)
+    return timed(solver(distance_matrix, options, result))
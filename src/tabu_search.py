@@ -1,15 +1,65 @@
 import random
+from dataclasses import dataclass, replace
 from typing import Callable, Dict, List, Literal, Optional, Tuple
 
-from utils.data_types import AlgorithmName, DistanceMatrix, Tour
+from utils.data_types import AlgorithmName, DistanceMatrix, ProgressCallback, Tour
 from utils.tour import random_tour, tour_length
+from utils.local_search import IMPROVEMENT_EPSILON, delta_two_opt
 from utils.data_loader import load_tsp_dataset, TspDataset
 from utils.monitoring import AlgorithmResult, ResultMonitor
-from utils.timing import measure_execution_time
+from utils.timing import Deadline, timed
 
 Neighborhood = Literal["swap", "insert", "two_opt"]
-Move = Tuple[Neighborhood, int, int]
-TabuList = Dict[Move, int]
+# Tabu attribute: the (smaller, larger) pair of cities exchanged by a move
+CityPair = Tuple[int, int]
+# City pair -> iteration at which its tabu status expires
+TabuList = Dict[CityPair, int]
+# Move: the (i, j) position pair passed to the neighborhood's apply function
+Move = Tuple[int, int]
+
+
+@dataclass
+class TabuConfig:
+    """Parametry algorytmu Tabu Search."""
+
+    neighborhood: Neighborhood = "two_opt"
+    max_iters: int = 500
+    # Liczba iteracji, przez które para zamienionych miast pozostaje tabu
+    tabu_tenure: int = 10
+    # Zatrzymanie po tylu iteracjach bez poprawy najlepszej trasy (None - brak)
+    max_no_improve: Optional[int] = 400
+    # Liczba losowanych ruchów na iterację (None - pełne sąsiedztwo)
+    max_candidates: Optional[int] = None
+    # Limit czasu obliczeń w sekundach (None - brak)
+    max_duration_s: Optional[float] = None
+    seed: Optional[int] = None
+
+    def validate(self) -> None:
+        """Sprawdza poprawność parametrów."""
+        if self.neighborhood not in ("swap", "insert", "two_opt"):
+            raise ValueError(f"Unknown neighborhood: {self.neighborhood!r}")
+        if self.max_iters <= 0:
+            raise ValueError("max_iters must be > 0")
+        if self.tabu_tenure <= 0:
+            raise ValueError("tabu_tenure must be > 0")
+        if self.max_no_improve is not None and self.max_no_improve <= 0:
+            raise ValueError("max_no_improve must be > 0 when provided")
+        if self.max_candidates is not None and self.max_candidates <= 0:
+            raise ValueError("max_candidates must be > 0 when provided")
+        if self.max_duration_s is not None and self.max_duration_s <= 0:
+            raise ValueError("max_duration_s must be > 0 when provided")
+
+    def to_parameters(self) -> Dict[str, str]:
+        """Zwraca parametry w postaci do zapisania w AlgorithmResult."""
+        return {
+            "neighborhood": self.neighborhood,
+            "max_iters": str(self.max_iters),
+            "tabu_tenure": str(self.tabu_tenure),
+            "max_no_improve": str(self.max_no_improve),
+            "max_candidates": str(self.max_candidates),
+            "max_duration_s": str(self.max_duration_s),
+            "seed": str(self.seed),
+        }
 
 
 def _apply_swap(tour: Tour, i: int, j: int) -> Tour:
@@ -35,6 +85,13 @@ def _apply_two_opt(tour: Tour, i: int, j: int) -> Tour:
     return neighbor
 
 
+_APPLY_FN: Dict[Neighborhood, Callable[[Tour, int, int], Tour]] = {
+    "swap": _apply_swap,
+    "insert": _apply_insert,
+    "two_opt": _apply_two_opt,
+}
+
+
 def _move_generator(
     n: int,
     neighborhood: Neighborhood,
@@ -43,6 +100,7 @@ def _move_generator(
 ) -> List[Tuple[int, int]]:
     """Generate (i, j) move pairs.
 
+    For two_opt the pair (0, n-1) is skipped - reversing the whole tour changes no edge.
     If max_candidates is provided, it samples that many unique move pairs (or fewer if impossible).
     This is practical for larger instances to control runtime in parameter sweeps.
     """
@@ -50,20 +108,24 @@ def _move_generator(
 
     if neighborhood in ("swap", "two_opt"):
         # i < j
+        whole_tour = (0, n - 1) if neighborhood == "two_opt" else None
         if max_candidates is None:
             for i in range(n - 1):
                 for j in range(i + 1, n):
-                    all_pairs.append((i, j))
+                    if (i, j) != whole_tour:
+                        all_pairs.append((i, j))
             return all_pairs
 
         seen = set()
-        target = min(max_candidates, (n * (n - 1)) // 2)
+        pair_count = (n * (n - 1)) // 2 - (1 if whole_tour is not None and n >= 2 else 0)
+        target = min(max_candidates, pair_count)
         attempts = 0
         attempt_cap = target * 50 + 100
         while len(seen) < target and attempts < attempt_cap:
             i = rng.randrange(0, n - 1)
             j = rng.randrange(i + 1, n)
-            seen.add((i, j))
+            if (i, j) != whole_tour:
+                seen.add((i, j))
             attempts += 1
         return list(seen)
 
@@ -87,8 +149,15 @@ def _move_generator(
     return list(seen)
 
 
+def _city_pair(tour: Tour, i: int, j: int) -> CityPair:
+    """Tabu attribute of move (i, j): the pair of cities at positions i and j."""
+    a, b = tour[i], tour[j]
+    return (a, b) if a < b else (b, a)
+
+
 def _best_admissible_neighbor(
     tour: Tour,
+    tour_len: float,
     distance_matrix: DistanceMatrix,
     tabu_list: TabuList,
     iteration: int,
@@ -96,32 +165,35 @@ def _best_admissible_neighbor(
     neighborhood: Neighborhood,
     rng: random.Random,
     max_candidates: Optional[int],
-) -> Tuple[Optional[Tour], float, Optional[Move]]:
+    use_delta: bool,
+) -> Tuple[Optional[Move], float, Optional[CityPair], bool]:
     """Select best neighbor under tabu + aspiration.
 
     Aspiration criterion: allow tabu move if it improves the global best.
+    Tabu status is a single dict lookup per move (no scan of the tabu list).
+    With use_delta (two_opt on a symmetric matrix) a move is scored as
+    tour_len + delta_two_opt instead of recomputing the neighbor's length.
+    Only the returned move is meant to be applied (see _apply_move).
+
+    Returns (move, neighbor_len, city_pair, aspiration_used).
     """
     n = len(tour)
-    apply_fn: Callable[[Tour, int, int], Tour]
-    if neighborhood == "swap":
-        apply_fn = _apply_swap
-    elif neighborhood == "insert":
-        apply_fn = _apply_insert
-    else:  # two_opt
-        apply_fn = _apply_two_opt
-
-    best_tour: Optional[Tour] = None
-    best_len = float("inf")
+    apply_fn = _APPLY_FN[neighborhood]
+
     best_move: Optional[Move] = None
+    best_len = float("inf")
+    best_pair: Optional[CityPair] = None
+    best_is_tabu = False
 
     candidates = _move_generator(n, neighborhood, rng, max_candidates)
     for i, j in candidates:
-        move: Move = (neighborhood, i, j)
-        expires_at = tabu_list.get(move, -1)
-        is_tabu = expires_at > iteration
+        pair = _city_pair(tour, i, j)
+        is_tabu = tabu_list.get(pair, -1) > iteration
 
-        neighbor = apply_fn(tour, i, j)
-        neighbor_len = tour_length(neighbor, distance_matrix)
+        if use_delta:
+            neighbor_len = tour_len + delta_two_opt(tour, distance_matrix, i, j)
+        else:
+            neighbor_len = tour_length(apply_fn(tour, i, j), distance_matrix)
 
         # Tabu unless aspiration triggers.
         if is_tabu and neighbor_len >= best_overall_len:
@@ -129,76 +201,85 @@ def _best_admissible_neighbor(
 
         if neighbor_len < best_len:
             best_len = neighbor_len
-            best_tour = neighbor
-            best_move = move
+            best_move = (i, j)
+            best_pair = pair
+            best_is_tabu = is_tabu
 
-    return best_tour, best_len, best_move
+    return best_move, best_len, best_pair, best_is_tabu
 
 
 def tabu_search(
     distance_matrix: DistanceMatrix,
-    *,
-    neighborhood: Neighborhood = "two_opt",
-    max_iters: int = 2000,
-    max_no_improve: Optional[int] = 400,
-    tabu_tenure: int = 20,
+    config: TabuConfig,
     start_tour: Optional[Tour] = None,
-    seed: Optional[int] = None,
-    max_candidates: Optional[int] = None,
-) -> Tuple[Tour, float, int]:
+    progress: Optional[ProgressCallback] = None,
+) -> Tuple[Tour, float, int, int, List[float], bool]:
     """Tabu Search for TSP.
 
     Project-aligned features:
-    - 3 neighborhoods: swap / insert / two_opt (parameter: neighborhood)
-    - tabu list with tenure (parameter: tabu_tenure)
+    - 3 neighborhoods: swap / insert / two_opt (config.neighborhood)
+    - tabu list of recently exchanged city pairs with tenure (config.tabu_tenure)
     - aspiration criterion (tabu move allowed if it improves the global best)
-    - stop criteria: max_iters and/or max_no_improve (parameter: max_no_improve)
-    - optional candidate sampling (parameter: max_candidates) for runtime control
+    - stop criteria: max_iters and/or max_no_improve (config.max_no_improve)
+    - optional wall-clock limit (config.max_duration_s), checked before each iteration
+    - optional candidate sampling (config.max_candidates) for runtime control
+    - optional progress callback invoked after each iteration with (iteration, best_len)
+
+    Returns (best_tour, best_len, iterations, aspiration_overrides,
+    best-so-far length after each iteration, timed_out).
     """
-    if max_iters <= 0:
-        raise ValueError("max_iters must be > 0")
-    if tabu_tenure <= 0:
-        raise ValueError("tabu_tenure must be > 0")
-    if max_no_improve is not None and max_no_improve <= 0:
-        raise ValueError("max_no_improve must be > 0 when provided")
+    config.validate()
+    deadline = Deadline(config.max_duration_s)
 
     n = len(distance_matrix)
-    rng = random.Random(seed)
+    rng = random.Random(config.seed)
+    # delta_two_opt assumes a symmetric matrix; other cases recompute full lengths
+    use_delta = config.neighborhood == "two_opt" and distance_matrix.is_symmetric(
+        IMPROVEMENT_EPSILON
+    )
 
-    current_tour = start_tour if start_tour is not None else random_tour(n)
+    current_tour = start_tour if start_tour is not None else random_tour(n, rng)
     current_len = tour_length(current_tour, distance_matrix)
 
     best_tour = current_tour
     best_len = current_len
 
+    # Expired entries are never removed - a pair is tabu only while its
+    # expiry iteration lies in the future.
     tabu_list: TabuList = {}
     no_improve = 0
     iterations = 0
+    aspirations = 0
+    history: List[float] = []
+    timed_out = False
 
-    for iteration in range(max_iters):
+    for iteration in range(config.max_iters):
+        if iteration > 0 and deadline.expired():
+            timed_out = True
+            break
         iterations = iteration + 1
 
-        expired = [m for m, exp in tabu_list.items() if exp <= iteration]
-        for m in expired:
-            del tabu_list[m]
-
-        neighbor_tour, neighbor_len, move = _best_admissible_neighbor(
+        move, neighbor_len, pair, aspiration = _best_admissible_neighbor(
             current_tour,
+            current_len,
             distance_matrix,
             tabu_list,
             iteration,
             best_len,
-            neighborhood,
+            config.neighborhood,
             rng,
-            max_candidates,
+            config.max_candidates,
+            use_delta,
         )
 
-        if neighbor_tour is None or move is None:
+        if move is None or pair is None:
             break
 
-        current_tour = neighbor_tour
+        current_tour = _APPLY_FN[config.neighborhood](current_tour, *move)
         current_len = neighbor_len
-        tabu_list[move] = iteration + tabu_tenure
+        tabu_list[pair] = iteration + config.tabu_tenure
+        if aspiration:
+            aspirations += 1
 
         if current_len < best_len:
             best_len = current_len
@@ -206,49 +287,92 @@ def tabu_search(
             no_improve = 0
         else:
             no_improve += 1
-            if max_no_improve is not None and no_improve >= max_no_improve:
-                break
+        history.append(best_len)
+        if progress is not None:
+            progress(iterations, best_len)
+        if config.max_no_improve is not None and no_improve >= config.max_no_improve:
+            break
 
-    return best_tour, best_len, iterations
+    if use_delta:
+        # Length recomputed to avoid accumulated error from summing deltas
+        best_len = tour_length(best_tour, distance_matrix)
+    return best_tour, best_len, iterations, aspirations, history, timed_out
+
+
+def tabu_solver(
+    distance_matrix: DistanceMatrix,
+    config: TabuConfig,
+    result: AlgorithmResult,
+    progress: Optional[ProgressCallback] = None,
+    initial: Optional[Tour] = None,
+) -> Callable[[], AlgorithmResult]:
+    """
+    Zapisuje parametry config w wyniku i zwraca funkcję uruchamiającą Tabu Search
+    (start z initial, jeśli podano); używana przez run_tabu i polecenie solve.
+    """
+    for key, value in config.to_parameters().items():
+        result.with_parameter(key, value)
+
+    def solve() -> AlgorithmResult:
+        best_tour, best_len, iterations, aspirations, history, timed_out = tabu_search(
+            distance_matrix, config, start_tour=initial, progress=progress
+        )
+        result.set_result(best_len, best_tour)
+        result.set_iterations(iterations)
+        result.with_convergence(history)
+        result.with_metric("aspiration_overrides", float(aspirations))
+        result.with_metric("timed_out", float(timed_out))
+        return result
+
+    return solve
 
 
 def run_tabu(
     dataset: TspDataset = TspDataset.TSP_48,
-    max_iters: int = 500,
-    tabu_tenure: int = 10,
+    config: Optional[TabuConfig] = None,
+    progress: Optional[ProgressCallback] = None,
+    initial: Optional[Tour] = None,
+    initial_source: Optional[str] = None,
 ) -> AlgorithmResult:
     """
     Uruchamia Tabu Search i zapisuje wyniki.
 
     Args:
         dataset: Zbior danych TSP do przetworzenia
-        max_iters: Maksymalna liczba iteracji algorytmu
-        tabu_tenure: Dlugosc trwania tabu dla ruchu
+        config: Parametry algorytmu (domyślnie TabuConfig()); jeśli config.seed
+                jest None, ziarno jest losowane i zapisywane w wyniku
+        progress: Opcjonalna funkcja wywoływana po każdej iteracji
+        initial: Trasa startowa (np. route wcześniejszego wyniku) zamiast losowej
+        initial_source: Skąd pochodzi initial (zapisywane w parametrach wyniku)
+
+    Raises:
+        RouteError: Jeśli initial nie jest permutacją miast zbioru danych
     """
+    config = config or TabuConfig()
+    if config.seed is None:
+        config = replace(config, seed=random.randrange(2**32))
     distance_matrix = load_tsp_dataset(dataset)
 
     result = AlgorithmResult.new(AlgorithmName.TABU.value)
     result.with_dataset(dataset)
-    result.with_parameter("max_iters", str(max_iters))
-    result.with_parameter("tabu_tenure", str(tabu_tenure))
+    result.with_content_hash(distance_matrix)
+    solve = tabu_solver(distance_matrix, config, result, progress, initial)
+    if initial is not None:
+        result.with_warm_start(initial, len(distance_matrix), initial_source)
 
     print("Uruchamianie algorytmu tabu search...")
 
-    (best_tour, best_len, iterations), execution_time_s = measure_execution_time(
-        lambda: tabu_search(distance_matrix, max_iters=max_iters, tabu_tenure=tabu_tenure)
-    )
+    timed(solve)
 
     print("Algorytm tabu search zakończony.")
 
-    result.set_result(best_len, best_tour)
-    result.set_execution_time(execution_time_s)
-    result.set_iterations(iterations)
-
     monitor = ResultMonitor()
     filename = monitor.save_result(result)
     print(f"Wyniki zapisane do: {filename}")
-    print(f"Najlepsza długość trasy: {best_len:.2f}")
-    print(f"Czas wykonania: {execution_time_s:.3f} s")
+    print(f"Najlepsza długość trasy: {result.route_length:.2f}")
+    print(f"Czas wykonania: {result.execution_time_s:.3f} s")
+
+    return result
 
 
 if __name__ == "__main__":
@@ -0,0 +1,253 @@
+"""
+Moduł wielokrotnego uruchamiania algorytmów i agregowania wyników.
+"""
+
+import itertools
+import statistics
+from concurrent.futures import ProcessPoolExecutor
+from dataclasses import dataclass, field
+from typing import Any, Callable, Dict, List, Optional, Sequence, Tuple
+
+from utils.data_types import Tour
+from utils.monitoring import AlgorithmResult, ResultMonitor
+from utils.timing import measure_execution_time
+
+
+@dataclass
+class BenchmarkSummary:
+    """Statystyki serii uruchomień jednego algorytmu."""
+
+    runs: int
+    best_length: float
+    worst_length: float
+    mean_length: float
+    std_dev_length: float
+    mean_execution_time_s: float
+    std_dev_execution_time_s: float
+    best_route: Tour = field(default_factory=list)
+
+    def to_dict(self) -> dict:
+        """Konwertuje do słownika do serializacji JSON."""
+        return {
+            "runs": self.runs,
+            "best_length": self.best_length,
+            "worst_length": self.worst_length,
+            "mean_length": self.mean_length,
+            "std_dev_length": self.std_dev_length,
+            "mean_execution_time_s": self.mean_execution_time_s,
+            "std_dev_execution_time_s": self.std_dev_execution_time_s,
+            "best_route": self.best_route,
+        }
+
+
+def benchmark(
+    runs: int,
+    func: Callable[[], AlgorithmResult],
+    monitor: Optional[ResultMonitor] = None,
+) -> BenchmarkSummary:
+    """
+    Uruchamia algorytm 'runs' razy i agreguje wyniki.
+
+    Args:
+        runs: Liczba uruchomień
+        func: Funkcja bez argumentów zwracająca AlgorithmResult, np.:
+              lambda: run_genetic(TspDataset.TSP_48, config)
+        monitor: Jeśli podany, każdy wynik jest dodatkowo zapisywany tym monitorem
+                 (nie podawaj, jeśli func sam zapisuje wyniki)
+
+    Returns:
+        BenchmarkSummary: Statystyki długości trasy i czasu oraz najlepsza trasa
+
+    Raises:
+        ValueError: Jeśli runs <= 0
+    """
+    if runs <= 0:
+        raise ValueError("runs must be > 0")
+
+    results: List[AlgorithmResult] = []
+    for _ in range(runs):
+        result = func()
+        if monitor is not None:
+            monitor.save_result(result)
+        results.append(result)
+
+    summary = AlgorithmResult.summarize(results)
+    times = [r.execution_time_s for r in results]
+    best = min(results, key=lambda r: (r.route_length, r.execution_time_s))
+
+    return BenchmarkSummary(
+        runs=runs,
+        best_length=summary.best_length,
+        worst_length=summary.worst_length,
+        mean_length=summary.mean_length,
+        std_dev_length=summary.std_dev_length,
+        mean_execution_time_s=summary.mean_execution_time_s,
+        std_dev_execution_time_s=statistics.stdev(times) if len(times) > 1 else 0.0,
+        best_route=list(best.route),
+    )
+
+
+def resume_experiment(
+    monitor: ResultMonitor,
+    experiment_id: str,
+    runs: int,
+    func: Callable[[int], AlgorithmResult],
+) -> List[AlgorithmResult]:
+    """
+    Dokańcza serię 'runs' uruchomień eksperymentu po przerwaniu: liczy zapisane już
+    wyniki (ResultMonitor.count_for_experiment) i wykonuje tylko brakujące uruchomienia
+    o numerach count..runs-1. Każdy nowy wynik dostaje experiment_id i jest od razu
+    zapisywany, więc kolejne przerwanie traci co najwyżej bieżące uruchomienie.
+
+    Args:
+        monitor: Monitor katalogu wyników eksperymentu
+        experiment_id: Identyfikator eksperymentu
+        runs: Docelowa liczba uruchomień
+        func: Funkcja przyjmująca numer uruchomienia i zwracająca niezapisany wynik,
+              np. (ziarno zależne od numeru, więc seria jest powtarzalna):
+              lambda i: solve_instance(matrix, "genetic", SolveOptions(seed=i), name)
+
+    Returns:
+        List[AlgorithmResult]: Wyniki wykonane w tym wywołaniu (pusta lista, jeśli seria
+        była już kompletna)
+
+    Raises:
+        ValueError: Jeśli runs <= 0
+    """
+    if runs <= 0:
+        raise ValueError("runs must be > 0")
+
+    done = monitor.count_for_experiment(experiment_id)
+    results: List[AlgorithmResult] = []
+    for run_index in range(done, runs):
+        result = func(run_index)
+        result.with_experiment(experiment_id)
+        monitor.save_result(result)
+        results.append(result)
+    return results
+
+
+@dataclass
+class GridSearchResult:
+    """Wynik przeszukiwania siatki parametrów (grid_search)."""
+
+    # Najlepsza kombinacja parametrów (najmniejsza średnia długość trasy po ziarnach)
+    best_params: Dict[str, Any]
+    best_mean_length: float
+    # Najlepsze pojedyncze uruchomienie najlepszej kombinacji
+    best_result: AlgorithmResult
+    # Każda kombinacja ze średnią długością trasy, w kolejności uruchomień
+    combinations: List[Tuple[Dict[str, Any], float]] = field(default_factory=list)
+    # Wszystkie wyniki (zapisane w katalogu wyników)
+    results: List[AlgorithmResult] = field(default_factory=list)
+
+
+def grid_search(
+    grid: Dict[str, Sequence[Any]],
+    func: Callable[[Dict[str, Any], int], AlgorithmResult],
+    monitor: ResultMonitor,
+    experiment_id: str,
+    seeds: Sequence[int] = (0,),
+) -> GridSearchResult:
+    """
+    Przeszukuje siatkę parametrów: dla każdej kombinacji z iloczynu kartezjańskiego
+    wartości z grid i każdego ziarna wywołuje func(kombinacja, ziarno). Każdy wynik
+    dostaje parametry kombinacji (str(wartość)) i experiment_id, po czym jest od razu
+    zapisywany, więc całe przeszukiwanie da się odczytać przez
+    ResultMonitor.list_by_experiment.
+
+    Args:
+        grid: Nazwa parametru -> sprawdzane wartości, np.
+              {"mutation_rate": [0.01, 0.05], "population_size": [50, 100]}
+        func: Funkcja zwracająca niezapisany wynik dla kombinacji i ziarna, np.:
+              lambda params, seed: solve_ga(matrix, replace(base, seed=seed, **params))
+        monitor: Monitor katalogu, w którym zapisywane są wyniki
+        experiment_id: Identyfikator eksperymentu wspólny dla wszystkich uruchomień
+        seeds: Ziarna, z którymi uruchamiana jest każda kombinacja
+
+    Returns:
+        GridSearchResult: Kombinacja o najmniejszej średniej długości trasy
+        (remisy - pierwsza w kolejności uruchomień) oraz wszystkie wyniki
+
+    Raises:
+        ValueError: Jeśli któryś parametr nie ma wartości lub seeds jest puste
+    """
+    for key, values in grid.items():
+        if len(values) == 0:
+            raise ValueError(f"Parameter {key!r} has no values to search")
+    if len(seeds) == 0:
+        raise ValueError("seeds must not be empty")
+
+    keys = list(grid)
+    combinations: List[Tuple[Dict[str, Any], float]] = []
+    results: List[AlgorithmResult] = []
+    best: Optional[Tuple[float, Dict[str, Any], AlgorithmResult]] = None
+    for values in itertools.product(*(grid[key] for key in keys)):
+        params = dict(zip(keys, values))
+        runs = []
+        for seed in seeds:
+            result = func(dict(params), seed)
+            for key, value in params.items():
+                result.with_parameter(key, str(value))
+            result.with_experiment(experiment_id)
+            monitor.save_result(result)
+            runs.append(result)
+        results += runs
+
+        mean_length = statistics.mean(r.route_length for r in runs)
+        combinations.append((params, mean_length))
+        if best is None or mean_length < best[0]:
+            best = (mean_length, params, min(runs, key=lambda r: r.route_length))
+
+    best_mean_length, best_params, best_result = best
+    return GridSearchResult(
+        best_params=best_params,
+        best_mean_length=best_mean_length,
+        best_result=best_result,
+        combinations=combinations,
+        results=results,
+    )
+
+
+def multi_start(
+    starts: int,
+    func: Callable[[int], AlgorithmResult],
+    parallel: bool = True,
+    max_workers: Optional[int] = None,
+) -> AlgorithmResult:
+    """
+    Uruchamia func(start_index) dla start_index = 0..starts-1 i zwraca najlepszy wynik.
+
+    W trybie równoległym starty wykonywane są w osobnych procesach, więc func musi
+    dać się serializować (funkcja z poziomu modułu lub functools.partial, nie lambda).
+
+    Args:
+        starts: Liczba startów
+        func: Funkcja przyjmująca numer startu i zwracająca AlgorithmResult
+        parallel: True - pula procesów, False - wykonanie sekwencyjne
+        max_workers: Maksymalna liczba procesów (None - liczba rdzeni)
+
+    Returns:
+        AlgorithmResult: Wynik z najkrótszą trasą; execution_time_s to czas rzeczywisty
+        całego multistartu, a parametr "start_index" wskazuje zwycięski start
+
+    Raises:
+        ValueError: Jeśli starts <= 0
+    """
+    if starts <= 0:
+        raise ValueError("starts must be > 0")
+
+    def run_all() -> List[AlgorithmResult]:
+        if not parallel:
+            return [func(i) for i in range(starts)]
+        with ProcessPoolExecutor(max_workers=max_workers) as executor:
+            return list(executor.map(func, range(starts)))
+
+    results, execution_time_s = measure_execution_time(run_all)
+
+    best_index = min(range(starts), key=lambda i: results[i].route_length)
+    best = results[best_index]
+    best.with_parameter("start_index", str(best_index))
+    best.with_parameter("starts", str(starts))
+    best.set_execution_time(execution_time_s)
+    return best
@@ -0,0 +1,136 @@
+"""
+Moduł dolnych ograniczeń długości trasy TSP.
+
+Dolne ograniczenie nie jest długością trasy optymalnej - każda trasa (także optymalna)
+jest od niego nie krótsza. Pozwala oszacować jakość wyniku, gdy optimum nie jest znane:
+trasa jest co najwyżej o (długość - ograniczenie) / ograniczenie * 100% dłuższa
+od optymalnej.
+"""
+
+from typing import List, Tuple
+
+from utils.data_types import DistanceMatrix
+
+# Tolerancja sprawdzania symetrii macierzy
+SYMMETRY_EPSILON = 1e-9
+# Domyślna liczba iteracji optymalizacji subgradientowej Helda-Karpa
+DEFAULT_BOUND_ITERATIONS = 100
+# Po tylu iteracjach bez poprawy ograniczenia krok jest zmniejszany o połowę
+STEP_PATIENCE = 5
+
+
+def _one_tree(distance_matrix: DistanceMatrix, pi: List[float]) -> Tuple[float, List[int]]:
+    """
+    Waga minimalnego 1-drzewa dla kosztów c(i, j) + pi[i] + pi[j]: minimalne drzewo
+    rozpinające miast 1..n-1 (algorytm Prima, O(n^2)) i dwie najtańsze krawędzie
+    z miasta 0. Zwraca (waga, stopnie_miast).
+    """
+    n = len(distance_matrix)
+    degrees = [0] * n
+    in_tree = [False] * n
+    in_tree[0] = True
+    best_cost = [float("inf")] * n
+    best_parent = [-1] * n
+    best_cost[1] = 0.0
+    total = 0.0
+
+    for _ in range(n - 1):
+        city = min(
+            (c for c in range(1, n) if not in_tree[c]), key=lambda c: best_cost[c]
+        )
+        in_tree[city] = True
+        total += best_cost[city]
+        if best_parent[city] >= 0:
+            degrees[city] += 1
+            degrees[best_parent[city]] += 1
+        row = distance_matrix[city]
+        for other in range(1, n):
+            if not in_tree[other]:
+                cost = row[other] + pi[city] + pi[other]
+                if cost < best_cost[other]:
+                    best_cost[other] = cost
+                    best_parent[other] = city
+
+    edges_from_zero = sorted(
+        range(1, n), key=lambda c: distance_matrix[0][c] + pi[0] + pi[c]
+    )[:2]
+    for city in edges_from_zero:
+        total += distance_matrix[0][city] + pi[0] + pi[city]
+        degrees[city] += 1
+        degrees[0] += 1
+    return total, degrees
+
+
+def _nearest_neighbor_length(distance_matrix: DistanceMatrix) -> float:
+    """Długość trasy najbliższego sąsiada z miasta 0 (ograniczenie górne dla kroku)."""
+    n = len(distance_matrix)
+    unvisited = set(range(1, n))
+    current = 0
+    total = 0.0
+    while unvisited:
+        nxt = min(unvisited, key=lambda c: distance_matrix[current][c])
+        total += distance_matrix[current][nxt]
+        unvisited.discard(nxt)
+        current = nxt
+    return total + distance_matrix[current][0]
+
+
+def one_tree_lower_bound(
+    distance_matrix: DistanceMatrix, iterations: int = DEFAULT_BOUND_ITERATIONS
+) -> float:
+    """
+    Dolne ograniczenie Helda-Karpa (1-drzewo) długości trasy dla macierzy symetrycznej.
+
+    Waga minimalnego 1-drzewa nie przekracza długości żadnej trasy, bo trasa jest
+    1-drzewem, w którym każde miasto ma stopień 2. Optymalizacja subgradientowa
+    karze miasta o stopniu różnym od 2 (kary pi) i podnosi ograniczenie; przy
+    iterations = 0 zwracana jest waga zwykłego 1-drzewa. Koszt: O(iterations * n^2).
+
+    Wynik jest ograniczeniem dolnym, a nie długością trasy optymalnej.
+
+    Args:
+        distance_matrix: Symetryczna macierz odległości
+        iterations: Liczba iteracji optymalizacji subgradientowej
+
+    Raises:
+        ValueError: Jeśli macierz nie jest symetryczna lub iterations < 0
+    """
+    if iterations < 0:
+        raise ValueError("iterations must be >= 0")
+    if not distance_matrix.is_symmetric(SYMMETRY_EPSILON):
+        raise ValueError("The 1-tree lower bound requires a symmetric distance matrix")
+
+    n = len(distance_matrix)
+    if n < 3:
+        # Jedyna trasa przechodzi każdą krawędź tam i z powrotem
+        return sum(distance_matrix[0][c] + distance_matrix[c][0] for c in range(1, n))
+
+    upper = _nearest_neighbor_length(distance_matrix)
+    pi = [0.0] * n
+    best_bound = float("-inf")
+    step_scale = 2.0
+    no_improve = 0
+
+    for iteration in range(iterations + 1):
+        weight, degrees = _one_tree(distance_matrix, pi)
+        bound = weight - 2 * sum(pi)
+        if bound > best_bound:
+            best_bound = bound
+            no_improve = 0
+        else:
+            no_improve += 1
+            if no_improve >= STEP_PATIENCE:
+                step_scale /= 2
+                no_improve = 0
+
+        subgradient = [d - 2 for d in degrees]
+        norm = sum(g * g for g in subgradient)
+        if iteration == iterations or norm == 0:
+            # Stopnie równe 2 - 1-drzewo jest trasą, więc ograniczenie jest optymalne
+            break
+        step = step_scale * max(upper - bound, 0.0) / norm
+        if step <= 0:
+            break
+        pi = [p + step * g for p, g in zip(pi, subgradient)]
+
+    return min(best_bound, upper)
@@ -84,7 +84,7 @@ def load_tsp_data(file_path: str) -> DistanceMatrix:
                 
                 matrix.append(processed_row)
         
-        return matrix
+        return DistanceMatrix(matrix)
     
     except FileNotFoundError:
         raise FileNotFoundError(f"Failed to open file: {file_path}")
@@ -2,15 +2,480 @@
 Moduł definiujący typy danych używane w projekcie TSP.
 """
 
+import csv
+import hashlib
+import heapq
+import json
+import math
+from dataclasses import dataclass, field
 from enum import Enum
-from typing import List
+from pathlib import Path
+from typing import Callable, List, Optional, Sequence, Tuple
 
-# Typ dla macierzy odległości TSP
-DistanceMatrix = List[List[float]]
+class SymmetrizeMode(Enum):
+    """Sposób łączenia odległości d(a, b) i d(b, a) przy symetryzacji macierzy."""
+
+    # Mniejsza z dwóch odległości
+    MIN = "min"
+    # Średnia z dwóch odległości
+    AVG = "avg"
+
+
+class RoundingMode(Enum):
+    """Zaokrąglanie odległości przy budowie macierzy (zgodnie z konwencją instancji)."""
+
+    # Odległości rzeczywiste bez zaokrąglania
+    NONE = "none"
+    # Do najbliższej liczby całkowitej, jak nint w TSPLIB (np. EUC_2D)
+    NEAREST = "nearest"
+    # W górę do liczby całkowitej (np. CEIL_2D w TSPLIB)
+    CEIL = "ceil"
+
+    def apply(self, value: float) -> float:
+        """Zaokrągla odległość; wartości nieskończone pozostają bez zmian."""
+        if self == RoundingMode.NONE or math.isinf(value):
+            return value
+        if self == RoundingMode.NEAREST:
+            return float(math.floor(value + 0.5))
+        return float(math.ceil(value))
+
+
+# Średni promień Ziemi w kilometrach (dla DistanceMetric.HAVERSINE)
+EARTH_RADIUS_KM = 6371.0
+
+
+class DistanceMetric(Enum):
+    """Sposób liczenia odległości między miastami na podstawie współrzędnych."""
+
+    # Odległość euklidesowa na płaszczyźnie, współrzędne (x, y)
+    EUCLIDEAN = "euclidean"
+    # Odległość miejska |dx| + |dy|, współrzędne (x, y)
+    MANHATTAN = "manhattan"
+    # Odległość po kole wielkim w kilometrach, współrzędne (szerokość, długość) w stopniach
+    HAVERSINE = "haversine"
+
+    def distance(self, a: Tuple[float, float], b: Tuple[float, float]) -> float:
+        """Zwraca odległość między punktami a i b w tej metryce."""
+        if self == DistanceMetric.EUCLIDEAN:
+            return math.hypot(a[0] - b[0], a[1] - b[1])
+        if self == DistanceMetric.MANHATTAN:
+            return float(abs(a[0] - b[0]) + abs(a[1] - b[1]))
+        lat_a, lon_a, lat_b, lon_b = map(math.radians, (a[0], a[1], b[0], b[1]))
+        h = (
+            math.sin((lat_b - lat_a) / 2) ** 2
+            + math.cos(lat_a) * math.cos(lat_b) * math.sin((lon_b - lon_a) / 2) ** 2
+        )
+        return 2 * EARTH_RADIUS_KM * math.asin(min(1.0, math.sqrt(h)))
+
+
+class MatrixIssue(Enum):
+    """Rodzaj problemu wykrytego przez DistanceMatrix.validate."""
+
+    # Macierz nie jest kwadratowa (pozostałe sprawdzenia są wtedy pomijane)
+    NOT_SQUARE = "not_square"
+    # Ujemna odległość
+    NEGATIVE = "negative"
+    # Wartość NaN
+    NAN = "nan"
+    # Odległość nieskończona (np. brakująca krawędź z from_edge_list_csv)
+    INFINITE = "infinite"
+    # Odległość miasta do samego siebie różna od zera
+    NONZERO_DIAGONAL = "nonzero_diagonal"
+    # d(a, b) != d(b, a) - poprawne dla instancji asymetrycznych (ATSP)
+    ASYMMETRIC = "asymmetric"
+    # d(a, c) > d(a, b) + d(b, c) - część instancji celowo ją narusza
+    TRIANGLE_INEQUALITY = "triangle_inequality"
+
+    @property
+    def is_error(self) -> bool:
+        """Czy problem czyni macierz niepoprawną (a nie tylko nietypową)."""
+        return self in (
+            MatrixIssue.NOT_SQUARE,
+            MatrixIssue.NEGATIVE,
+            MatrixIssue.NAN,
+            MatrixIssue.NONZERO_DIAGONAL,
+        )
+
+
+# Tyle przykładowych par (lub trójek) miast zapamiętuje MatrixWarning
+MATRIX_WARNING_EXAMPLES = 5
+
+
+@dataclass
+class MatrixWarning:
+    """Problem jednego rodzaju wykryty w macierzy odległości."""
+
+    issue: MatrixIssue
+    # Liczba wystąpień problemu (par miast, trójek lub wierszy)
+    count: int = 0
+    # Pierwsze wystąpienia: (wiersz,) dla NOT_SQUARE, (a, b) lub (a, b, c)
+    # dla TRIANGLE_INEQUALITY
+    examples: List[Tuple[int, ...]] = field(default_factory=list)
+
+    @property
+    def is_error(self) -> bool:
+        """Czy problem jest błędem (a nie tylko ostrzeżeniem) - patrz MatrixIssue."""
+        return self.issue.is_error
+
+    def add(self, example: Tuple[int, ...]) -> None:
+        """Zlicza kolejne wystąpienie problemu."""
+        self.count += 1
+        if len(self.examples) < MATRIX_WARNING_EXAMPLES:
+            self.examples.append(example)
+
+    def __str__(self) -> str:
+        level = "błąd" if self.is_error else "ostrzeżenie"
+        return f"{level}: {self.issue.value} ({self.count}x, np. {self.examples})"
+
+
+class DistanceMatrix(List[List[float]]):
+    """
+    Macierz odległości TSP.
+
+    Lista 2D, gdzie matrix[i][j] to odległość z miasta i do miasta j.
+    Dziedziczy po liście, więc zwykłe indeksowanie matrix[i][j] nadal działa.
+    Macierz może być asymetryczna (ATSP) - d(a, b) nie musi być równe d(b, a).
+    """
+
+    # Metryka, z której zbudowano macierz (from_coords); None dla macierzy wczytanych
+    # wprost z odległości
+    metric: Optional[DistanceMetric] = None
+    # Zaokrąglanie zastosowane przy budowie macierzy
+    rounding: RoundingMode = RoundingMode.NONE
+    # Licznik zmian wykonanych przez update_edge - pozwala wykryć nieaktualne,
+    # zapamiętane długości tras (patrz utils.tour.TrackedTour)
+    version: int = 0
+    # Mnożnik przeliczający odległości (i długości tras) na jednostki oryginalnej
+    # macierzy - różny od 1.0 tylko dla macierzy z normalized()
+    scale: float = 1.0
+
+    def distance(self, a: int, b: int) -> float:
+        """Zwraca odległość z miasta a do miasta b."""
+        return self[a][b]
+
+    def is_symmetric(self, epsilon: float = 0.0) -> bool:
+        """Sprawdza, czy |d(a, b) - d(b, a)| <= epsilon dla każdej pary miast."""
+        n = len(self)
+        return all(
+            abs(self[i][j] - self[j][i]) <= epsilon
+            for i in range(n)
+            for j in range(i + 1, n)
+        )
+
+    def update_edge(
+        self, a: int, b: int, new_cost: float, symmetric: Optional[bool] = None
+    ) -> None:
+        """
+        Zmienia koszt krawędzi a -> b (dynamiczny TSP) i zwiększa version.
+
+        Args:
+            a: Miasto początkowe
+            b: Miasto końcowe
+            new_cost: Nowy koszt krawędzi
+            symmetric: Czy zmienić też krawędź b -> a; None - tylko jeśli macierz jest
+                symetryczna (sprawdzenie O(n^2), w pętlach lepiej podać wprost)
+
+        Raises:
+            ValueError: Jeśli a lub b jest spoza zakresu, a == b lub koszt to NaN
+        """
+        n = len(self)
+        if not (0 <= a < n and 0 <= b < n):
+            raise ValueError(f"Edge ({a}, {b}) is out of range for {n} cities")
+        if a == b:
+            raise ValueError("Cannot update the diagonal of the distance matrix")
+        if math.isnan(new_cost):
+            raise ValueError("Edge cost must not be NaN")
+
+        if symmetric is None:
+            symmetric = self.is_symmetric()
+        self[a][b] = new_cost
+        if symmetric:
+            self[b][a] = new_cost
+        self.version += 1
+
+    def normalized(self) -> "DistanceMatrix":
+        """
+        Zwraca nową macierz przeskalowaną tak, aby największa skończona odległość
+        poza przekątną wynosiła 1.0. Współczynnik skali jest zapamiętywany w atrybucie
+        scale (łącznie ze skalą macierzy źródłowej), więc denormalize przelicza
+        długości tras z powrotem na jednostki oryginalne. Metryka jest zachowana,
+        a zaokrąglanie - nie (wartości przestają być całkowite).
+
+        Raises:
+            ValueError: Jeśli macierz nie ma żadnej dodatniej, skończonej odległości
+        """
+        n = len(self)
+        largest = max(
+            (
+                self[i][j]
+                for i in range(n)
+                for j in range(n)
+                if i != j and math.isfinite(self[i][j])
+            ),
+            default=0.0,
+        )
+        if largest <= 0:
+            raise ValueError("Cannot normalize a matrix without positive finite distances")
+        matrix = DistanceMatrix([[value / largest for value in row] for row in self])
+        matrix.metric = self.metric
+        matrix.scale = self.scale * largest
+        return matrix
+
+    def denormalize(self, length: float) -> float:
+        """Przelicza odległość lub długość trasy z tej macierzy na jednostki oryginalne."""
+        return length * self.scale
+
+    def symmetrize(self, mode: SymmetrizeMode = SymmetrizeMode.MIN) -> "DistanceMatrix":
+        """Zwraca nową, symetryczną macierz (min lub średnia z obu kierunków)."""
+        n = len(self)
+        combine = min if mode == SymmetrizeMode.MIN else (lambda x, y: (x + y) / 2.0)
+        return DistanceMatrix(
+            [[combine(self[i][j], self[j][i]) for j in range(n)] for i in range(n)]
+        )
+
+    @classmethod
+    def _rounded(cls, rows: List[List[float]], rounding: RoundingMode) -> "DistanceMatrix":
+        """Tworzy macierz z zaokrąglonych wartości i zapamiętuje tryb zaokrąglania."""
+        if rounding != RoundingMode.NONE:
+            rows = [[rounding.apply(value) for value in row] for row in rows]
+        matrix = cls(rows)
+        matrix.rounding = rounding
+        return matrix
+
+    @classmethod
+    def from_coords(
+        cls,
+        coords: Sequence[Tuple[float, float]],
+        metric: DistanceMetric,
+        rounding: RoundingMode = RoundingMode.NONE,
+    ) -> "DistanceMatrix":
+        """
+        Buduje macierz odległości ze współrzędnych miast w podanej metryce.
+
+        Args:
+            coords: Współrzędne miast (indeks = numer miasta); dla HAVERSINE
+                    (szerokość, długość) w stopniach
+            metric: Metryka odległości (zapamiętywana w atrybucie metric)
+            rounding: Zaokrąglanie odległości (zapamiętywane w atrybucie rounding)
+
+        Returns:
+            DistanceMatrix: Symetryczna macierz z zerami na przekątnej
+        """
+        matrix = cls._rounded([[metric.distance(a, b) for b in coords] for a in coords], rounding)
+        matrix.metric = metric
+        return matrix
+
+    @classmethod
+    def from_full_matrix_file(
+        cls, path: Path, rounding: RoundingMode = RoundingMode.NONE
+    ) -> "DistanceMatrix":
+        """
+        Wczytuje pełną macierz odległości z pliku tekstowego.
+
+        Każdy niepusty wiersz pliku to jeden wiersz macierzy, wartości rozdzielone
+        są białymi znakami.
+
+        Args:
+            path: Ścieżka do pliku z macierzą
+            rounding: Zaokrąglanie odległości (zapamiętywane w atrybucie rounding)
+
+        Returns:
+            DistanceMatrix: Kwadratowa macierz odległości
+
+        Raises:
+            FileNotFoundError: Jeśli plik nie może zostać otwarty
+            ValueError: Jeśli wartość nie jest liczbą, wiersze mają różne długości
+                        lub liczba wierszy nie odpowiada liczbie kolumn
+        """
+        try:
+            with open(path, "r", encoding="utf-8") as file:
+                lines = file.read().splitlines()
+        except FileNotFoundError:
+            raise FileNotFoundError(f"Failed to open file: {path}")
+
+        matrix: List[List[float]] = []
+        for line_no, line in enumerate(lines, start=1):
+            tokens = line.split()
+            if not tokens:
+                continue
+            try:
+                row = [float(token) for token in tokens]
+            except ValueError as e:
+                raise ValueError(f"Line {line_no}: {e}")
+            if matrix and len(row) != len(matrix[0]):
+                raise ValueError(
+                    f"Line {line_no}: expected {len(matrix[0])} values, got {len(row)}"
+                )
+            matrix.append(row)
+
+        if matrix and len(matrix) != len(matrix[0]):
+            raise ValueError(
+                f"Matrix is not square: {len(matrix)} rows, {len(matrix[0])} columns"
+            )
+        return cls._rounded(matrix, rounding)
+
+    @classmethod
+    def from_edge_list_csv(
+        cls,
+        path: Path,
+        num_cities: int,
+        symmetric: bool = True,
+        delimiter: str = ",",
+        rounding: RoundingMode = RoundingMode.NONE,
+    ) -> "DistanceMatrix":
+        """
+        Buduje macierz odległości z pliku CSV z wierszami "from,to,distance"
+        (miasta numerowane od 0, opcjonalny wiersz nagłówka).
+
+        Brakujące odległości mają wartość math.inf (na przekątnej 0), więc algorytm
+        nie poprowadzi trasy przez niezdefiniowaną krawędź; listę brakujących par
+        zwraca missing_pairs().
+
+        Args:
+            path: Ścieżka do pliku CSV
+            num_cities: Liczba miast (rozmiar macierzy)
+            symmetric: Jeśli True, każdy wiersz ustawia odległość w obu kierunkach
+            delimiter: Separator pól
+            rounding: Zaokrąglanie odległości (zapamiętywane w atrybucie rounding)
+
+        Returns:
+            DistanceMatrix: Macierz num_cities x num_cities
+
+        Raises:
+            FileNotFoundError: Jeśli plik nie może zostać otwarty
+            ValueError: Jeśli wiersz nie ma trzech pól, wartość nie jest liczbą
+                        lub indeks miasta jest spoza zakresu 0..num_cities-1
+        """
+        if num_cities < 0:
+            raise ValueError("num_cities must be >= 0")
+
+        matrix = [
+            [0.0 if i == j else math.inf for j in range(num_cities)]
+            for i in range(num_cities)
+        ]
+        try:
+            with open(path, "r", encoding="utf-8-sig", newline="") as file:
+                rows = list(csv.reader(file, delimiter=delimiter))
+        except FileNotFoundError:
+            raise FileNotFoundError(f"Failed to open file: {path}")
+
+        for line_no, row in enumerate(rows, start=1):
+            if not row or all(not field.strip() for field in row):
+                continue
+            if len(row) != 3:
+                raise ValueError(f"Line {line_no}: expected 3 fields, got {len(row)}")
+            try:
+                a, b = int(row[0]), int(row[1])
+                distance = float(row[2])
+            except ValueError:
+                if line_no == 1:
+                    # Wiersz nagłówka, np. "from,to,distance"
+                    continue
+                raise ValueError(f"Line {line_no}: invalid entry {delimiter.join(row)!r}")
+            for city in (a, b):
+                if not 0 <= city < num_cities:
+                    raise ValueError(
+                        f"Line {line_no}: city index {city} out of range 0..{num_cities - 1}"
+                    )
+            matrix[a][b] = distance
+            if symmetric:
+                matrix[b][a] = distance
+
+        return cls._rounded(matrix, rounding)
+
+    def missing_pairs(self) -> List[Tuple[int, int]]:
+        """Zwraca pary miast (a, b), a != b, bez zdefiniowanej odległości (math.inf)."""
+        n = len(self)
+        return [
+            (i, j) for i in range(n) for j in range(n) if i != j and math.isinf(self[i][j])
+        ]
+
+    def validate(self, epsilon: float = 1e-9) -> List[MatrixWarning]:
+        """
+        Sprawdza poprawność macierzy przed uruchomieniem algorytmów i zwraca wykryte
+        problemy, po jednym MatrixWarning na rodzaj (pusta lista - brak problemów).
+        Błędy (MatrixWarning.is_error): macierz niekwadratowa, ujemne odległości, NaN,
+        niezerowa przekątna. Ostrzeżenia: odległości nieskończone, asymetria
+        i naruszenia nierówności trójkąta (sprawdzenie O(n^3), tylko dla skończonych
+        odległości).
+
+        Args:
+            epsilon: Tolerancja porównań przy sprawdzaniu symetrii i nierówności trójkąta
+        """
+        n = len(self)
+        not_square = MatrixWarning(MatrixIssue.NOT_SQUARE)
+        for i, row in enumerate(self):
+            if len(row) != n:
+                not_square.add((i,))
+        if not_square.count:
+            return [not_square]
+
+        found = {issue: MatrixWarning(issue) for issue in MatrixIssue}
+        for i in range(n):
+            for j in range(n):
+                value = self[i][j]
+                if math.isnan(value):
+                    found[MatrixIssue.NAN].add((i, j))
+                    continue
+                if value < 0:
+                    found[MatrixIssue.NEGATIVE].add((i, j))
+                if math.isinf(value):
+                    found[MatrixIssue.INFINITE].add((i, j))
+                if i == j and value != 0:
+                    found[MatrixIssue.NONZERO_DIAGONAL].add((i, j))
+                if i < j and abs(value - self[j][i]) > epsilon:
+                    found[MatrixIssue.ASYMMETRIC].add((i, j))
+
+        isfinite = math.isfinite
+        for b in range(n):
+            row_b = self[b]
+            for a in range(n):
+                d_ab = self[a][b]
+                if a == b or not isfinite(d_ab):
+                    continue
+                row_a = self[a]
+                for c in range(n):
+                    if c == a or c == b or not isfinite(row_b[c]) or not isfinite(row_a[c]):
+                        continue
+                    if row_a[c] > d_ab + row_b[c] + epsilon:
+                        found[MatrixIssue.TRIANGLE_INEQUALITY].add((a, b, c))
+
+        return [warning for warning in found.values() if warning.count]
+
+    def nearest_neighbors(self, k: int) -> List[List[int]]:
+        """
+        Zwraca listy sąsiadów: dla każdego miasta k najbliższych innych miast
+        posortowanych rosnąco wg odległości (remisy wg numeru miasta).
+        Dla k >= n - 1 listy zawierają wszystkie pozostałe miasta.
+
+        Raises:
+            ValueError: Jeśli k < 0
+        """
+        if k < 0:
+            raise ValueError("k must be >= 0")
+        n = len(self)
+        return [
+            heapq.nsmallest(k, (c for c in range(n) if c != city), key=lambda c: (row[c], c))
+            for city, row in enumerate(self)
+        ]
+
+    def content_hash(self) -> str:
+        """
+        Zwraca skrót SHA-256 (hex) zawartości macierzy.
+
+        Skrót zależy tylko od wartości odległości (liczby całkowite traktowane są jak
+        float), więc pozwala wykryć, że plik instancji zmienił się między uruchomieniami.
+        """
+        rows = [[float(value) for value in row] for row in self]
+        payload = json.dumps(rows, separators=(",", ":"))
+        return hashlib.sha256(payload.encode("utf-8")).hexdigest()
 
 # Typ dla trasy (listy miast)
 Tour = List[int]
 
+# Funkcja wywoływana po każdej iteracji zewnętrznej algorytmu: (iteracja, najlepsza_długość)
+ProgressCallback = Callable[[int, float], None]
+
 
 class AlgorithmName(Enum):
     """Enum reprezentujący nazwy dostępnych algorytmów TSP."""
@@ -19,3 +484,23 @@ class AlgorithmName(Enum):
     IHC = "ihc"
     # Tabu Search
     TABU = "tabu"
+    # Nearest Neighbor
+    NN = "nearest_neighbor"
+    # Genetic Algorithm
+    GA = "genetic"
+    # Simulated Annealing
+    SA = "simulated_annealing"
+    # Ant Colony Optimization
+    ACO = "aco"
+    # Greedy Edge
+    GREEDY_EDGE = "greedy_edge"
+    # Cheapest Insertion
+    CHEAPEST_INSERTION = "cheapest_insertion"
+    # Farthest Insertion
+    FARTHEST_INSERTION = "farthest_insertion"
+    # Losowa trasa (punkt odniesienia)
+    RANDOM = "random"
+    # Iterated Local Search
+    ILS = "ils"
+    # Przegląd zupełny (dokładny, tylko małe instancje)
+    BRUTE_FORCE = "brute_force"
@@ -0,0 +1,534 @@
+"""
+Moduł lokalnych przeszukiwań poprawiających gotową trasę.
+
+Funkcje modyfikują trasę w miejscu i zwracają liczbę wykonanych ruchów poprawiających.
+Zmiana długości liczona jest przyrostowo (tylko na zmienianych krawędziach),
+bez przeliczania całej trasy.
+
+Ruchy odwracające fragment trasy zakładają macierz symetryczną - dla macierzy
+asymetrycznej (ATSP) przeszukiwania zgłaszają ValueError zamiast liczyć błędne delty.
+"""
+
+from collections import deque
+from typing import Iterable, List, Optional, Tuple
+
+from utils.data_types import DistanceMatrix, Tour
+
+# Minimalna poprawa uznawana za poprawę (chroni przed zapętleniem na błędach zaokrągleń)
+IMPROVEMENT_EPSILON = 1e-9
+
+
+def require_symmetric(distance_matrix: DistanceMatrix) -> None:
+    """
+    Sprawdza, czy macierz jest symetryczna (wymagane przez ruchy odwracające fragment trasy).
+
+    Raises:
+        ValueError: Jeśli macierz nie jest symetryczna
+    """
+    if not distance_matrix.is_symmetric(IMPROVEMENT_EPSILON):
+        raise ValueError("This local search assumes a symmetric distance matrix")
+
+
+def delta_two_opt(route: Tour, distance_matrix: DistanceMatrix, i: int, j: int) -> float:
+    """
+    Zmiana długości trasy po odwróceniu fragmentu route[i..j] (0 <= i <= j < n).
+
+    Zakłada trasę zamkniętą (krawędź z ostatniego miasta do pierwszego) i macierz
+    symetryczną. Liczy tylko cztery zmieniane krawędzie: (route[i-1], route[i])
+    i (route[j], route[j+1]) zastępowane są przez (route[i-1], route[j])
+    i (route[i], route[j+1]).
+    """
+    n = len(route)
+    if i == 0 and j == n - 1:
+        # Odwrócenie całej trasy nie zmienia krawędzi
+        return 0.0
+    a, b = route[i - 1], route[i]
+    c, d = route[j], route[(j + 1) % n]
+    return (
+        distance_matrix[a][c]
+        + distance_matrix[b][d]
+        - distance_matrix[a][b]
+        - distance_matrix[c][d]
+    )
+
+
+def two_opt(
+    route: Tour, distance_matrix: DistanceMatrix, neighbors: Optional[int] = None
+) -> int:
+    """
+    Przeszukiwanie 2-opt: dopóki istnieje ruch poprawiający, odwraca fragment
+    route[i+1..j], zastępując krawędzie (a, b) i (c, d) krawędziami (a, c) i (b, d).
+
+    Jeśli podano neighbors, rozważane są tylko ruchy, w których nowa krawędź (a, c)
+    prowadzi do jednego z neighbors najbliższych miast a (DistanceMatrix.nearest_neighbors).
+    Jeden przebieg ma wtedy złożoność O(n * neighbors) zamiast O(n^2), kosztem
+    pominięcia nielicznych ruchów poprawiających.
+
+    Zakłada macierz symetryczną (odwrócony fragment ma tę samą długość).
+
+    Returns:
+        int: Liczba wykonanych ruchów poprawiających
+
+    Raises:
+        ValueError: Jeśli neighbors < 1 lub macierz nie jest symetryczna
+    """
+    if neighbors is not None and neighbors < 1:
+        raise ValueError("neighbors must be >= 1 when provided")
+    require_symmetric(distance_matrix)
+
+    if neighbors is not None:
+        candidates = distance_matrix.nearest_neighbors(neighbors)
+        return _two_opt_neighbors(route, distance_matrix, candidates)
+
+    n = len(route)
+    moves = 0
+    improved = True
+
+    while improved:
+        improved = False
+        for i in range(n - 1):
+            for j in range(i + 2, n):
+                # Dla i == 0 i j == n-1 krawędzie są sąsiednie - ruch nic nie zmienia
+                if i == 0 and j == n - 1:
+                    continue
+                if delta_two_opt(route, distance_matrix, i + 1, j) < -IMPROVEMENT_EPSILON:
+                    route[i + 1 : j + 1] = reversed(route[i + 1 : j + 1])
+                    moves += 1
+                    improved = True
+
+    return moves
+
+
+def _reverse_cyclic(route: Tour, positions: List[int], start: int, end: int) -> None:
+    """
+    Odwraca cykliczny fragment trasy od pozycji start do end (włącznie).
+    Odwracany jest krótszy z dwóch równoważnych fragmentów (fragment albo jego dopełnienie).
+    """
+    n = len(route)
+    length = (end - start) % n + 1
+    if length * 2 > n:
+        start, end = (end + 1) % n, (start - 1) % n
+        length = n - length
+    for k in range(length // 2):
+        p, q = (start + k) % n, (end - k) % n
+        route[p], route[q] = route[q], route[p]
+        positions[route[p]] = p
+        positions[route[q]] = q
+
+
+def _two_opt_neighbors(
+    route: Tour, distance_matrix: DistanceMatrix, candidates: List[List[int]]
+) -> int:
+    """2-opt z ruchami ograniczonymi do list najbliższych sąsiadów (patrz two_opt)."""
+    n = len(route)
+    if n < 4:
+        return 0
+
+    positions = [0] * n
+    for idx, city in enumerate(route):
+        positions[city] = idx
+
+    moves = 0
+    improved = True
+    while improved:
+        improved = False
+        for a in range(n):
+            # Krawędź (a, następnik) zastępowana jest przez (a, c) i (b, następnik c);
+            # symetrycznie dla krawędzi (poprzednik, a)
+            for forward in (True, False):
+                step = 1 if forward else -1
+                i = positions[a]
+                b = route[(i + step) % n]
+                for c in candidates[a]:
+                    # Listy posortowane rosnąco - dalsze miasta nie dadzą poprawy
+                    if distance_matrix[a][c] >= distance_matrix[a][b]:
+                        break
+                    j = positions[c]
+                    d = route[(j + step) % n]
+                    if c == b or d == a:
+                        continue
+                    delta = (
+                        distance_matrix[a][c]
+                        + distance_matrix[b][d]
+                        - distance_matrix[a][b]
+                        - distance_matrix[c][d]
+                    )
+                    if delta < -IMPROVEMENT_EPSILON:
+                        if forward:
+                            _reverse_cyclic(route, positions, (i + 1) % n, j)
+                        else:
+                            _reverse_cyclic(route, positions, i, positions[d])
+                        moves += 1
+                        improved = True
+                        break
+
+    return moves
+
+
+def two_opt_dlb(route: Tour, distance_matrix: DistanceMatrix) -> int:
+    """
+    Przeszukiwanie 2-opt z bitami "don't look".
+
+    Każde miasto ma bit "nie sprawdzaj"; miasta z ustawionym bitem są pomijane.
+    Bit miasta jest ustawiany, gdy żadna z jego dwóch krawędzi w trasie nie daje
+    ruchu poprawiającego, a czyszczony dla czterech końców krawędzi zmienionych
+    przez wykonany ruch. Na dużych instancjach sprawdza znacznie mniej par krawędzi
+    niż two_opt przy porównywalnej jakości.
+
+    Returns:
+        int: Liczba wykonanych ruchów poprawiających
+
+    Raises:
+        ValueError: Jeśli macierz nie jest symetryczna
+    """
+    require_symmetric(distance_matrix)
+
+    n = len(route)
+    if n < 4:
+        return 0
+
+    positions = [0] * n
+    for idx, city in enumerate(route):
+        positions[city] = idx
+
+    dont_look = [False] * n
+    active = deque(route)
+    moves = 0
+
+    while active:
+        city = active.popleft()
+        dont_look[city] = True
+
+        # Krawędź wychodząca (city, następnik) i wchodząca (poprzednik, city)
+        for i in (positions[city], (positions[city] - 1) % n):
+            a, b = route[i], route[(i + 1) % n]
+            improved = False
+            for j in range(n):
+                if j == i or j == (i + 1) % n or (j + 1) % n == i:
+                    continue
+                c, d = route[j], route[(j + 1) % n]
+                delta = (
+                    distance_matrix[a][c]
+                    + distance_matrix[b][d]
+                    - distance_matrix[a][b]
+                    - distance_matrix[c][d]
+                )
+                if delta < -IMPROVEMENT_EPSILON:
+                    _reverse_cyclic(route, positions, (i + 1) % n, j)
+                    moves += 1
+                    for endpoint in (a, b, c, d):
+                        if dont_look[endpoint]:
+                            dont_look[endpoint] = False
+                            active.append(endpoint)
+                    improved = True
+                    break
+            if improved:
+                break
+
+    return moves
+
+
+def _neighbor_insertion_edges(
+    route: Tour, positions: List[int], i: int, length: int, candidates: List[List[int]]
+) -> Iterable[Tuple[int, int]]:
+    """
+    Krawędzie (u, v) trasy bez fragmentu route[i..i+length-1], których jeden koniec
+    jest na liście sąsiadów pierwszego lub ostatniego miasta fragmentu.
+    """
+    n = len(route)
+    segment = set(route[i : i + length])
+    first, last = route[i], route[i + length - 1]
+    prev, nxt = route[i - 1], route[(i + length) % n]
+
+    seen = set()
+    for c in candidates[first] + candidates[last]:
+        if c in segment or c in seen:
+            continue
+        seen.add(c)
+        pos = positions[c]
+        succ, pred = route[(pos + 1) % n], route[pos - 1]
+        # Po wycięciu fragmentu prev i nxt stają się sąsiadami
+        yield c, (nxt if succ == first else succ)
+        yield (prev if pred == last else pred), c
+
+
+def or_opt(
+    route: Tour,
+    distance_matrix: DistanceMatrix,
+    max_segment: int = 3,
+    neighbors: Optional[int] = None,
+) -> int:
+    """
+    Przeszukiwanie Or-opt: przenosi spójne fragmenty trasy o długości 1..max_segment
+    w lepsze miejsce (w oryginalnej lub odwróconej kolejności), dopóki istnieje
+    ruch poprawiający.
+
+    Działa na tej samej reprezentacji co two_opt, więc obie funkcje można
+    wywoływać na przemian, aż żadna nie znajdzie poprawy.
+
+    Jeśli podano neighbors, fragment wstawiany jest tylko obok jednego z neighbors
+    najbliższych miast jego pierwszego lub ostatniego miasta, zamiast sprawdzać
+    wszystkie krawędzie trasy.
+
+    Zakłada macierz symetryczną (fragmenty mogą być wstawiane w odwróconej kolejności).
+
+    Returns:
+        int: Liczba wykonanych przeniesień poprawiających
+
+    Raises:
+        ValueError: Jeśli max_segment < 1, neighbors < 1 lub macierz nie jest symetryczna
+    """
+    if max_segment < 1:
+        raise ValueError("max_segment must be >= 1")
+    if neighbors is not None and neighbors < 1:
+        raise ValueError("neighbors must be >= 1 when provided")
+    require_symmetric(distance_matrix)
+
+    candidates = None if neighbors is None else distance_matrix.nearest_neighbors(neighbors)
+    n = len(route)
+    positions = [0] * n
+    moves = 0
+    improved = True
+
+    while improved:
+        improved = False
+        for idx, city in enumerate(route):
+            positions[city] = idx
+        for length in range(1, min(max_segment, n - 3) + 1):
+            for i in range(n - length + 1):
+                first, last = route[i], route[i + length - 1]
+                prev, nxt = route[i - 1], route[(i + length) % n]
+                removal_gain = (
+                    distance_matrix[prev][first]
+                    + distance_matrix[last][nxt]
+                    - distance_matrix[prev][nxt]
+                )
+
+                if candidates is None:
+                    rest = route[:i] + route[i + length :]
+                    m = len(rest)
+                    edges = ((rest[k], rest[(k + 1) % m]) for k in range(m))
+                else:
+                    edges = _neighbor_insertion_edges(route, positions, i, length, candidates)
+                best_delta = -IMPROVEMENT_EPSILON
+                best_move = None
+                for u, v in edges:
+                    if u == prev and v == nxt:
+                        continue
+                    base = distance_matrix[u][v]
+                    forward = distance_matrix[u][first] + distance_matrix[last][v] - base
+                    backward = distance_matrix[u][last] + distance_matrix[first][v] - base
+                    if forward - removal_gain < best_delta:
+                        best_delta = forward - removal_gain
+                        best_move = (u, False)
+                    if backward - removal_gain < best_delta:
+                        best_delta = backward - removal_gain
+                        best_move = (u, True)
+
+                if best_move is not None:
+                    u, reverse = best_move
+                    rest = route[:i] + route[i + length :]
+                    k = rest.index(u)
+                    segment = route[i : i + length]
+                    if reverse:
+                        segment.reverse()
+                    route[:] = rest[: k + 1] + segment + rest[k + 1 :]
+                    moves += 1
+                    improved = True
+                    if candidates is None:
+                        break
+                    # Z listami sąsiadów przegląd trwa dalej (bez powrotu na początek),
+                    # aby jeden przebieg pozostał prawie liniowy
+                    for idx, city in enumerate(route):
+                        positions[city] = idx
+            if improved and candidates is None:
+                break
+
+    return moves
+
+
+def three_opt(route: Tour, distance_matrix: DistanceMatrix) -> int:
+    """
+    Przeszukiwanie 3-opt: usuwa trzy krawędzie trasy i wybiera najlepsze z siedmiu
+    możliwych ponownych połączeń, dopóki istnieje ruch poprawiający.
+
+    Dla cięć przed pozycjami i < j < k trasa to P + S1 + S2 + R, gdzie
+    S1 = route[i..j-1] i S2 = route[j..k-1]. Rozważane są połączenia
+    (X' oznacza odwrócony fragment):
+    - S1' S2, S1 S2', S2' S1' - ruchy równoważne 2-opt
+    - S1' S2', S2 S1, S2 S1', S2' S1 - właściwe ruchy 3-opt
+
+    Jeden przebieg ma złożoność O(n^3) - zalecane dla instancji do kilkuset miast.
+    Zakłada macierz symetryczną (fragmenty mogą być odwracane).
+
+    Returns:
+        int: Liczba wykonanych ruchów poprawiających
+
+    Raises:
+        ValueError: Jeśli macierz nie jest symetryczna
+    """
+    require_symmetric(distance_matrix)
+
+    n = len(route)
+    if n < 4:
+        return 0
+
+    d = distance_matrix
+    moves = 0
+    improved = True
+
+    while improved:
+        improved = False
+        for i in range(1, n - 1):
+            for j in range(i + 1, n):
+                for k in range(j + 1, n + 1):
+                    # Usuwane krawędzie: (a, b), (c, e) i (f, g)
+                    a, b = route[i - 1], route[i]
+                    c, e = route[j - 1], route[j]
+                    f, g = route[k - 1], route[k % n]
+                    removed = d[a][b] + d[c][e] + d[f][g]
+
+                    # Zmiana długości dla każdego z siedmiu połączeń
+                    deltas = (
+                        d[a][c] + d[b][e] + d[f][g] - removed,  # S1' S2
+                        d[a][b] + d[c][f] + d[e][g] - removed,  # S1 S2'
+                        d[a][f] + d[e][c] + d[b][g] - removed,  # S2' S1'
+                        d[a][c] + d[b][f] + d[e][g] - removed,  # S1' S2'
+                        d[a][e] + d[f][b] + d[c][g] - removed,  # S2 S1
+                        d[a][e] + d[f][c] + d[b][g] - removed,  # S2 S1'
+                        d[a][f] + d[e][b] + d[c][g] - removed,  # S2' S1
+                    )
+                    case = min(range(len(deltas)), key=deltas.__getitem__)
+                    if deltas[case] >= -IMPROVEMENT_EPSILON:
+                        continue
+
+                    s1, s2 = route[i:j], route[j:k]
+                    reconnections = (
+                        lambda: s1[::-1] + s2,
+                        lambda: s1 + s2[::-1],
+                        lambda: s2[::-1] + s1[::-1],
+                        lambda: s1[::-1] + s2[::-1],
+                        lambda: s2 + s1,
+                        lambda: s2 + s1[::-1],
+                        lambda: s2[::-1] + s1,
+                    )
+                    route[i:k] = reconnections[case]()
+                    moves += 1
+                    improved = True
+
+    return moves
+
+
+def _lk_neighbor(route: Tour, positions: List[int], city: int, forward: bool) -> int:
+    """Następnik (forward=True) lub poprzednik miasta w trasie."""
+    n = len(route)
+    return route[(positions[city] + (1 if forward else -1)) % n]
+
+
+def lin_kernighan(
+    route: Tour, distance_matrix: DistanceMatrix, max_depth: int = 5, neighbors: int = 10
+) -> int:
+    """
+    Uproszczony Lin-Kernighan: sekwencyjna wymiana krawędzi o ograniczonej głębokości.
+
+    Ruch startuje od krawędzi x1 = (t1, t2) i w każdym kroku i:
+    - dodaje krawędź y_i = (t2i, t2i+1) do jednego z najbliższych sąsiadów t2i,
+    - usuwa krawędź x_i+1 = (t2i+1, t2i+2), wybraną tak, aby zamknięcie krawędzią
+      (t2i+2, t1) dawało poprawną trasę (każdy krok to odwrócenie fragmentu, jak w 2-opt).
+
+    Kryterium zysku: skumulowany zysk G_i = sum(|x|) - sum(|y|) musi być dodatni
+    po każdym dodaniu krawędzi y. Krawędzi dodanych w ruchu nie wolno usuwać,
+    a usuniętych - dodawać ponownie. Łańcuch kończy się po max_depth krokach lub
+    gdy nie ma dopuszczalnego kroku; trasa wraca do stanu o największym zysku
+    zamknięcia G_i - |(t2i+2, t1)|, a jeśli żaden nie był dodatni - do stanu wyjściowego.
+
+    Args:
+        route: Trasa modyfikowana w miejscu
+        distance_matrix: Symetryczna macierz odległości
+        max_depth: Maksymalna liczba kroków (wymian krawędzi) w jednym ruchu
+        neighbors: Liczba najbliższych miast rozważanych jako t2i+1
+
+    Returns:
+        int: Liczba wykonanych ruchów poprawiających
+
+    Raises:
+        ValueError: Jeśli max_depth < 1, neighbors < 1 lub macierz nie jest symetryczna
+    """
+    if max_depth < 1:
+        raise ValueError("max_depth must be >= 1")
+    if neighbors < 1:
+        raise ValueError("neighbors must be >= 1")
+    require_symmetric(distance_matrix)
+
+    n = len(route)
+    if n < 5:
+        return two_opt(route, distance_matrix)
+
+    d = distance_matrix
+    candidates = distance_matrix.nearest_neighbors(neighbors)
+    positions = [0] * n
+    for idx, city in enumerate(route):
+        positions[city] = idx
+
+    def lk_move(t1: int, forward: bool) -> bool:
+        """Próbuje jednego ruchu LK od t1; zwraca True, jeśli trasa została skrócona."""
+        start_route = route.copy()
+        t2 = _lk_neighbor(route, positions, t1, forward)
+        gain = d[t1][t2]
+        removed = {frozenset((t1, t2))}
+        added = set()
+        best_gain, best_route = IMPROVEMENT_EPSILON, None
+
+        for _ in range(max_depth):
+            step = None
+            best_lookahead = -float("inf")
+            for t3 in candidates[t2]:
+                y = frozenset((t2, t3))
+                if gain - d[t2][t3] <= 0:
+                    break  # sąsiedzi posortowani rosnąco - dalsi też nie spełnią kryterium
+                if t3 in (t1, _lk_neighbor(route, positions, t2, forward)) or y in removed:
+                    continue
+                t4 = _lk_neighbor(route, positions, t3, not forward)
+                x = frozenset((t3, t4))
+                if t4 in (t1, t2) or x in added or x in removed:
+                    continue
+                lookahead = d[t3][t4] - d[t2][t3]
+                if lookahead > best_lookahead:
+                    best_lookahead, step = lookahead, (t3, t4, x, y)
+            if step is None:
+                break
+
+            t3, t4, x, y = step
+            # Odwrócenie fragmentu t2..t4: usuwa (t1, t2) i (t4, t3), dodaje (t2, t3) i (t1, t4)
+            if forward:
+                _reverse_cyclic(route, positions, positions[t2], positions[t4])
+            else:
+                _reverse_cyclic(route, positions, positions[t4], positions[t2])
+            gain += d[t3][t4] - d[t2][t3]
+            added.add(y)
+            removed.add(x)
+
+            closing_gain = gain - d[t4][t1]
+            if closing_gain > best_gain:
+                best_gain, best_route = closing_gain, route.copy()
+
+            # _reverse_cyclic mogło odwrócić dopełnienie - ustal kierunek od t1 do t4
+            forward = _lk_neighbor(route, positions, t1, True) == t4
+            t2 = t4
+
+        route[:] = best_route if best_route is not None else start_route
+        for idx, city in enumerate(route):
+            positions[city] = idx
+        return best_route is not None
+
+    moves = 0
+    improved = True
+    while improved:
+        improved = False
+        for t1 in range(n):
+            for forward in (True, False):
+                if lk_move(t1, forward):
+                    moves += 1
+                    improved = True
+
+    return moves
@@ -4,13 +4,221 @@ Moduł monitorowania wyników algorytmów TSP.
 Ten moduł zapewnia kompleksowe śledzenie i analizę wyników algorytmów TSP.
 """
 
+import csv
+import gzip
+import io
 import json
+import pickle
+import re
+import statistics
+import subprocess
+import time
 from dataclasses import dataclass, field
 from datetime import datetime, timezone
+from functools import lru_cache
 from pathlib import Path
-from typing import Dict, List
+from typing import Callable, Dict, List, Optional, Tuple, Union
 
 from utils.data_loader import TspDataset
+from utils.data_types import DistanceMatrix, DistanceMetric, RoundingMode
+from utils.tour import (
+    DEFAULT_LENGTH_TOLERANCE,
+    RouteError,
+    apply_swaps,
+    edge_lengths,
+    lengths_match,
+    rotate_to_start,
+    route_swaps,
+    tour_length,
+    validate_route,
+)
+
+# Wzorzec nazwy pliku wyniku:
+# {algorytm}_{liczba_miast}cities_{YYYYmmdd_HHMMSS_mmm}[_{n}].json[.gz] (lub .bin)
+# (opcjonalny sufiks _{n} dodawany jest przy kolizji nazw)
+RESULT_FILENAME_PATTERN = re.compile(
+    r"^(?P<algorithm>.+)_(?P<size>\d+)cities_(?P<timestamp>\d{8}_\d{6}_\d{3})"
+    r"(?:_(?P<suffix>\d+))?\.(?:json(?:\.gz)?|bin)$"
+)
+
+# Zawartość pliku wyniku: tekst JSON lub bajty pliku .bin
+ResultPayload = Union[str, bytes]
+
+
+class _ResultUnpickler(pickle.Unpickler):
+    """
+    Unpickler plików .bin dopuszczający tylko typy wbudowane (słowniki, listy, napisy,
+    liczby) - plik wyniku nie może odtworzyć dowolnego obiektu ani wykonać kodu.
+    """
+
+    def find_class(self, module: str, name: str):
+        raise pickle.UnpicklingError(f"Forbidden type in result file: {module}.{name}")
+
+# Rozszerzenia plików wyników: zwykły JSON i JSON skompresowany gzipem
+RESULT_EXTENSION = ".json"
+COMPRESSED_RESULT_EXTENSION = ".json.gz"
+# Rozszerzenie binarnych plików wyników (with_binary) - ten sam słownik co w JSON,
+# zapisany przez pickle
+BINARY_RESULT_EXTENSION = ".bin"
+RESULT_EXTENSIONS = (RESULT_EXTENSION, COMPRESSED_RESULT_EXTENSION, BINARY_RESULT_EXTENSION)
+# Stały protokół pickle, aby pliki .bin były czytelne także w nowszych wersjach Pythona
+BINARY_PICKLE_PROTOCOL = 4
+
+# Rozszerzenie tekstowych raportów wyników (save_report)
+REPORT_EXTENSION = ".txt"
+
+# Nazwa pliku zbiorczego dziennika wyników (jeden wynik JSON w każdej linii)
+JSONL_FILENAME = "results.jsonl"
+
+# Podkatalog katalogu wyników, do którego check_integrity(repair=True) przenosi
+# uszkodzone pliki
+CORRUPT_DIRNAME = "corrupt"
+
+# Bieżąca wersja schematu plików wyników. Historia:
+# 1 - pierwotny format (bez pola schema_version)
+# 2 - dodane pola convergence, instance_name i code_version
+# 3 - dodane pole experiment_id
+# 4 - zapis różnicowy trasy: opcjonalne pola base_filename i route_swaps
+# 5 - dodane pole content_hash i opcjonalne pole distance_matrix
+# 6 - dodane pole metric_series
+# 7 - opcjonalne pole distance_metric przy zapisanej macierzy odległości
+# 8 - dodane pole known_optimum
+# 9 - opcjonalne pole distance_rounding przy zapisanej macierzy odległości
+# 10 - opcjonalne pole coords (współrzędne miast instancji)
+# 11 - dodane pole tags
+# 12 - dodane pole is_closed (trasa zamknięta lub otwarta ścieżka)
+SCHEMA_VERSION = 12
+
+
+@lru_cache(maxsize=1)
+def code_version() -> Optional[str]:
+    """
+    Zwraca skrócony hash bieżącego commita git projektu (lub None poza repozytorium git).
+    """
+    project_root = Path(__file__).parent.parent.parent
+    try:
+        output = subprocess.run(
+            ["git", "rev-parse", "--short", "HEAD"],
+            cwd=project_root,
+            capture_output=True,
+            text=True,
+            check=True,
+        )
+    except (OSError, subprocess.CalledProcessError):
+        return None
+    return output.stdout.strip() or None
+
+
+def parse_result_filename(filename: str) -> Optional[Tuple[str, datetime]]:
+    """
+    Odczytuje nazwę algorytmu i znacznik czasu (UTC) z nazwy pliku wyniku.
+
+    Returns:
+        Optional[Tuple[str, datetime]]: (nazwa_algorytmu, znacznik_czasu) lub None,
+        jeśli nazwa pliku nie pasuje do wzorca
+    """
+    match = RESULT_FILENAME_PATTERN.match(filename)
+    if match is None:
+        return None
+    timestamp = datetime.strptime(match.group("timestamp") + "000", "%Y%m%d_%H%M%S_%f")
+    return match.group("algorithm"), timestamp.replace(tzinfo=timezone.utc)
+
+
+def result_sort_key(filename: str) -> tuple:
+    """
+    Klucz sortowania plików wyników w kolejności uruchomień: znacznik czasu z nazwy,
+    następnie sufiks kolizji (_1, _2, ...). Pliki spoza wzorca nazw trafiają na koniec
+    w kolejności alfabetycznej; nazwa rozstrzyga pozostałe remisy.
+    """
+    match = RESULT_FILENAME_PATTERN.match(filename)
+    if match is not None:
+        try:
+            timestamp = datetime.strptime(match.group("timestamp"), "%Y%m%d_%H%M%S_%f")
+        except ValueError:
+            pass
+        else:
+            return (0, timestamp, int(match.group("suffix") or 0), filename)
+    return (1, filename)
+
+
+def optimality_gap(result: "AlgorithmResult", optimal_length: float) -> float:
+    """
+    Zwraca odległość znalezionej trasy od optimum w procentach:
+    (znaleziona - optymalna) / optymalna * 100.
+
+    Raises:
+        ValueError: Jeśli optimal_length <= 0
+    """
+    if optimal_length <= 0:
+        raise ValueError("optimal_length must be > 0")
+    return (result.route_length - optimal_length) / optimal_length * 100.0
+
+
+def improvement_events(convergence: List[float]) -> List[Tuple[int, float]]:
+    """
+    Zwraca iteracje (numerowane od 1, jak w convergence), w których najlepsza
+    dotychczasowa długość trasy się poprawiła, razem z wielkością poprawy:
+    [(iteracja, o_ile_krótsza), ...]. Pierwsza iteracja nie jest poprawą - ustala
+    wartość początkową.
+    """
+    return [
+        (i + 1, convergence[i - 1] - convergence[i])
+        for i in range(1, len(convergence))
+        if convergence[i] < convergence[i - 1]
+    ]
+
+
+def longest_plateau(convergence: List[float]) -> int:
+    """Najdłuższa seria kolejnych iteracji bez poprawy najlepszej długości trasy."""
+    longest = current = 0
+    for i in range(1, len(convergence)):
+        current = current + 1 if convergence[i] >= convergence[i - 1] else 0
+        longest = max(longest, current)
+    return longest
+
+
+def migrate_result_dict(data: dict) -> dict:
+    """
+    Uzupełnia słownik wyniku zapisany w starszej wersji schematu do bieżącej wersji.
+
+    Brak pola schema_version oznacza wersję 1. Brakujące pola otrzymują wartości
+    domyślne, więc starsze pliki pozostają czytelne.
+
+    Returns:
+        dict: Nowy słownik w wersji SCHEMA_VERSION
+
+    Raises:
+        ValueError: Jeśli wersja schematu jest niepoprawna lub nowsza niż obsługiwana
+    """
+    if not isinstance(data, dict):
+        raise ValueError(f"Expected a JSON object, got {type(data).__name__}")
+    version = data.get("schema_version", 1)
+    if not isinstance(version, int) or version < 1:
+        raise ValueError(f"Invalid schema_version: {version!r}")
+    if version > SCHEMA_VERSION:
+        raise ValueError(
+            f"Unsupported schema_version {version} (newest supported: {SCHEMA_VERSION})"
+        )
+
+    migrated = dict(data)
+    if version < 2:
+        migrated.setdefault("convergence", [])
+        migrated.setdefault("instance_name", None)
+        migrated.setdefault("code_version", None)
+    if version < 3:
+        migrated.setdefault("experiment_id", None)
+    if version < 5:
+        migrated.setdefault("content_hash", None)
+    if version < 6:
+        migrated.setdefault("metric_series", {})
+    if version < 8:
+        migrated.setdefault("known_optimum", None)
+    if version < 11:
+        migrated.setdefault("tags", [])
+    if version < 12:
+        migrated.setdefault("is_closed", True)
+    migrated["schema_version"] = SCHEMA_VERSION
+    return migrated
 
 
 @dataclass
@@ -27,18 +235,140 @@ class AlgorithmResult:
         default_factory=lambda: datetime.now(timezone.utc)
     )
     additional_metrics: Dict[str, float] = field(default_factory=dict)
+    # Metryki zbierane w każdej iteracji (np. różnorodność populacji w każdym pokoleniu)
+    metric_series: Dict[str, List[float]] = field(default_factory=dict)
     dataset_size: int = 0
     dataset_name: str = ""
+    # Najlepsza dotychczasowa długość trasy po każdej iteracji
+    convergence: List[float] = field(default_factory=list)
+    # Nazwa instancji problemu (np. "TSP_48" lub nazwa z pliku TSPLIB)
+    instance_name: Optional[str] = None
+    # Wersja kodu, który wygenerował wynik (skrócony hash commita git)
+    code_version: Optional[str] = None
+    # Identyfikator eksperymentu (serii uruchomień), do którego należy wynik
+    experiment_id: Optional[str] = None
+    # Dowolne etykiety wyniku (np. "tuned", "final", "debug"), bez powtórzeń
+    tags: List[str] = field(default_factory=list)
+    # Skrót zawartości macierzy odległości instancji (DistanceMatrix.content_hash)
+    content_hash: Optional[str] = None
+    # Znana długość trasy optymalnej instancji (jeśli jest znana)
+    known_optimum: Optional[float] = None
+    # Współrzędne miast instancji (indeks = numer miasta) - opcjonalne, zapisywane
+    # tylko, gdy są ustawione, aby wynik dało się narysować bez pliku instancji
+    coords: Optional[List[Tuple[float, float]]] = None
+    # Czy trasa jest cyklem (z powrotem do miasta startowego); False - otwarta ścieżka
+    # z route[0] do route[-1], której długość nie obejmuje krawędzi powrotu
+    is_closed: bool = True
+    # Wersja schematu pliku wyniku (wczytane starsze wyniki są migrowane do SCHEMA_VERSION)
+    schema_version: int = SCHEMA_VERSION
 
     @classmethod
     def new(cls, algorithm_name: str) -> "AlgorithmResult":
-        """Tworzy nowy AlgorithmResult z podaną nazwą algorytmu."""
-        return cls(algorithm_name=algorithm_name)
+        """Tworzy nowy AlgorithmResult z podaną nazwą algorytmu i bieżącą wersją kodu."""
+        return cls(algorithm_name=algorithm_name, code_version=code_version())
 
     def with_dataset(self, dataset: TspDataset) -> "AlgorithmResult":
-        """Ustawia informacje o zbiorze danych."""
+        """Ustawia informacje o zbiorze danych (również nazwę instancji)."""
         self.dataset_size = dataset.city_count()
         self.dataset_name = dataset.name()
+        self.instance_name = dataset.value
+        return self
+
+    def with_num_cities(self, num_cities: int) -> "AlgorithmResult":
+        """
+        Ustawia rozmiar instancji dla wyników spoza TspDataset (np. instancje z pliku,
+        podinstancje). Nie ma osobnego pola num_cities - wartość trafia do dataset_size,
+        tego samego pola, które ustawia with_dataset i które występuje w nazwie pliku
+        wyniku ({algorytm}_{dataset_size}cities_...).
+        """
+        self.dataset_size = num_cities
+        return self
+
+    def with_known_optimum(self, optimal_length: float) -> "AlgorithmResult":
+        """
+        Ustawia znaną długość trasy optymalnej. Jeśli wynik ma już trasę, metryka
+        optimality_gap (w procentach) jest liczona od razu, a w przeciwnym razie
+        przy set_result.
+
+        Raises:
+            ValueError: Jeśli optimal_length <= 0
+        """
+        if optimal_length <= 0:
+            raise ValueError("optimal_length must be > 0")
+        self.known_optimum = optimal_length
+        self._update_optimality_gap()
+        return self
+
+    def with_lower_bound(self, lower_bound: float) -> "AlgorithmResult":
+        """
+        Zapisuje dolne ograniczenie długości trasy (np. utils.bounds.one_tree_lower_bound)
+        jako metrykę lower_bound. Metryka lower_bound_gap (w procentach, liczona jak
+        optimality_gap) mówi, o ile najwyżej trasa jest dłuższa od optymalnej.
+
+        Raises:
+            ValueError: Jeśli lower_bound <= 0
+        """
+        if lower_bound <= 0:
+            raise ValueError("lower_bound must be > 0")
+        self.additional_metrics["lower_bound"] = lower_bound
+        self._update_optimality_gap()
+        return self
+
+    def _update_optimality_gap(self) -> None:
+        """
+        Przelicza metryki optimality_gap i lower_bound_gap, jeśli znane są trasa
+        i optimum lub dolne ograniczenie.
+        """
+        if not self.route:
+            return
+        if self.known_optimum is not None:
+            self.additional_metrics["optimality_gap"] = optimality_gap(self, self.known_optimum)
+        lower_bound = self.additional_metrics.get("lower_bound")
+        if lower_bound is not None:
+            self.additional_metrics["lower_bound_gap"] = optimality_gap(self, lower_bound)
+
+    def with_coords(self, coords: List[Tuple[float, float]]) -> "AlgorithmResult":
+        """Dołącza do wyniku współrzędne miast (np. do plot_route bez pliku instancji)."""
+        self.coords = [(float(x), float(y)) for x, y in coords]
+        return self
+
+    def with_instance(self, name: str) -> "AlgorithmResult":
+        """Ustawia nazwę instancji problemu."""
+        self.instance_name = name
+        return self
+
+    def with_content_hash(self, distance_matrix: DistanceMatrix) -> "AlgorithmResult":
+        """Zapamiętuje skrót macierzy odległości, na której uruchomiono algorytm."""
+        self.content_hash = distance_matrix.content_hash()
+        return self
+
+    def matches_instance(self, distance_matrix: DistanceMatrix) -> bool:
+        """
+        Sprawdza, czy wynik został uzyskany na macierzy o tej samej zawartości.
+
+        Raises:
+            ValueError: Jeśli wynik nie ma zapisanego content_hash (np. starszy plik)
+        """
+        if self.content_hash is None:
+            raise ValueError("Result has no content_hash to compare against")
+        return self.content_hash == distance_matrix.content_hash()
+
+    def with_experiment(self, experiment_id: str) -> "AlgorithmResult":
+        """Przypisuje wynik do eksperymentu (serii uruchomień)."""
+        self.experiment_id = experiment_id
+        return self
+
+    def with_tag(self, tag: str) -> "AlgorithmResult":
+        """
+        Dodaje etykietę wyniku (ponowne dodanie tej samej etykiety nic nie zmienia).
+
+        Raises:
+            ValueError: Jeśli etykieta jest pusta
+        """
+        if not tag:
+            raise ValueError("tag must not be empty")
+        if tag not in self.tags:
+            self.tags.append(tag)
         return self
 
     def with_parameter(self, key: str, value: str) -> "AlgorithmResult":
@@ -46,15 +376,76 @@ class AlgorithmResult:
         self.parameters[key] = value
         return self
 
+    def with_param_float(self, key: str, value: float) -> "AlgorithmResult":
+        """
+        Dodaje parametr liczbowy zapisany jako str(float(value)) - ten sam format co
+        to_parameters konfiguracji; get_param_float odczytuje dokładnie tę samą wartość.
+        """
+        self.parameters[key] = str(float(value))
+        return self
+
+    def with_param_int(self, key: str, value: int) -> "AlgorithmResult":
+        """Dodaje parametr całkowity zapisany jako str(int(value))."""
+        self.parameters[key] = str(int(value))
+        return self
+
+    def _param_string(self, key: str) -> Optional[str]:
+        """Zapisany parametr lub None, jeśli go brak albo zapisano wartość None."""
+        value = self.parameters.get(key)
+        return None if value is None or value == "None" else value
+
+    def get_param_float(self, key: str) -> Optional[float]:
+        """
+        Odczytuje parametr jako liczbę zmiennoprzecinkową.
+
+        Returns:
+            Optional[float]: Wartość lub None, jeśli parametru brak albo ma wartość "None"
+                             (nieustawiona opcja konfiguracji)
+
+        Raises:
+            ValueError: Jeśli zapisany parametr nie jest liczbą
+        """
+        value = self._param_string(key)
+        if value is None:
+            return None
+        try:
+            return float(value)
+        except ValueError:
+            raise ValueError(f"Parameter {key!r} is not a number: {value!r}")
+
+    def get_param_int(self, key: str) -> Optional[int]:
+        """
+        Odczytuje parametr jako liczbę całkowitą (np. seed, liczba iteracji).
+
+        Returns:
+            Optional[int]: Wartość lub None, jeśli parametru brak albo ma wartość "None"
+
+        Raises:
+            ValueError: Jeśli zapisany parametr nie jest liczbą całkowitą
+        """
+        value = self._param_string(key)
+        if value is None:
+            return None
+        try:
+            return int(value)
+        except ValueError:
+            raise ValueError(f"Parameter {key!r} is not an integer: {value!r}")
+
     def with_metric(self, key: str, value: float) -> "AlgorithmResult":
         """Dodaje dodatkową metrykę."""
         self.additional_metrics[key] = value
         return self
 
+    def with_series(self, key: str, values: List[float]) -> "AlgorithmResult":
+        """Dodaje serię wartości metryki (np. po jednej na iterację)."""
+        self.metric_series[key] = values
+        return self
+
     def set_result(self, route_length: float, route: List[int]) -> "AlgorithmResult":
-        """Ustawia wynik (długość trasy i trasę)."""
+        """Ustawia wynik (długość trasy i trasę); przy znanym optimum liczy optimality_gap."""
         self.route_length = route_length
         self.route = route
+        self._update_optimality_gap()
         return self
 
     def set_execution_time(self, time_s: float) -> "AlgorithmResult":
@@ -67,9 +458,104 @@ class AlgorithmResult:
         self.iterations = iterations
         return self
 
+    def with_convergence(self, convergence: List[float]) -> "AlgorithmResult":
+        """
+        Ustawia historię zbieżności (najlepsza dotychczasowa długość po każdej iteracji)
+        i zapisuje jej podsumowanie w metrykach: convergence_improvements (liczba popraw,
+        improvement_events), longest_plateau (najdłuższa seria iteracji bez poprawy)
+        i last_improvement_iteration (0, jeśli nie było poprawy). Długie plateau przed
+        końcem wskazuje na zbyt duży budżet iteracji lub limit stagnacji.
+        """
+        self.convergence = convergence
+        if convergence:
+            events = improvement_events(convergence)
+            self.additional_metrics["convergence_improvements"] = float(len(events))
+            self.additional_metrics["longest_plateau"] = float(longest_plateau(convergence))
+            self.additional_metrics["last_improvement_iteration"] = float(
+                events[-1][0] if events else 0
+            )
+        return self
+
+    def with_warm_start(
+        self, initial: List[int], num_cities: int, source: Optional[str] = None
+    ) -> "AlgorithmResult":
+        """
+        Zaznacza w parametrach, że uruchomienie wystartowało z gotowej trasy (np. route
+        wcześniej zapisanego wyniku): warm_start = "true" i warm_start_source = source
+        (np. nazwa pliku wyniku; "-", jeśli nie podano).
+
+        Raises:
+            RouteError: Jeśli initial nie jest permutacją num_cities miast
+        """
+        validate_route(initial, num_cities)
+        self.parameters["warm_start"] = "true"
+        self.parameters["warm_start_source"] = source or "-"
+        return self
+
+    def validate_route(self, num_cities: int) -> None:
+        """
+        Sprawdza, czy zapisana trasa jest poprawną permutacją num_cities miast.
+
+        Raises:
+            RouteError: Jeśli trasa jest niepoprawna (WrongLength, DuplicateCity, IndexOutOfRange)
+        """
+        validate_route(self.route, num_cities)
+
+    def with_open_tour(self) -> "AlgorithmResult":
+        """
+        Oznacza trasę jako otwartą ścieżkę (is_closed = False): compute_length
+        i verify_length pomijają krawędź powrotu do miasta startowego.
+        """
+        self.is_closed = False
+        return self
+
+    def compute_length(self, distance_matrix: DistanceMatrix) -> float:
+        """
+        Oblicza długość zapisanej trasy (z powrotem do miasta startowego, chyba że
+        trasa jest otwarta - is_closed).
+        """
+        return tour_length(self.route, distance_matrix, closed=self.is_closed)
+
+    def with_edge_stats(self, distance_matrix: DistanceMatrix) -> "AlgorithmResult":
+        """
+        Zapisuje statystyki długości krawędzi trasy (edge_lengths) jako metryki
+        min_edge_length, max_edge_length i mean_edge_length - pojedyncza bardzo długa
+        krawędź wskazuje miejsce, którego przeszukiwanie lokalne nie poprawiło.
+
+        Raises:
+            ValueError: Jeśli wynik nie ma trasy lub trasa nie ma krawędzi
+                (otwarta trasa z jednym miastem)
+        """
+        if not self.route:
+            raise ValueError("Result has no route (was set_result called?)")
+        lengths = edge_lengths(self.route, distance_matrix, closed=self.is_closed)
+        if not lengths:
+            raise ValueError("Result route has no edges")
+        self.additional_metrics["min_edge_length"] = min(lengths)
+        self.additional_metrics["max_edge_length"] = max(lengths)
+        self.additional_metrics["mean_edge_length"] = statistics.mean(lengths)
+        return self
+
+    def verify_length(
+        self,
+        distance_matrix: DistanceMatrix,
+        epsilon: Optional[float] = None,
+        relative_tolerance: float = DEFAULT_LENGTH_TOLERANCE,
+    ) -> bool:
+        """
+        Sprawdza, czy zapisana route_length zgadza się z długością trasy.
+
+        Domyślnie stosowana jest tolerancja względna (relative_tolerance * długość),
+        odporna na kumulację błędów zaokrągleń przy dużych instancjach; podanie
+        epsilon wymusza tolerancję bezwzględną.
+        """
+        return lengths_match(
+            self.compute_length(distance_matrix), self.route_length, epsilon, relative_tolerance
+        )
+
     def to_dict(self) -> dict:
         """Konwertuje do słownika do serializacji JSON."""
-        return {
+        data = {
             "algorithm_name": self.algorithm_name,
             "parameters": self.parameters,
             "route_length": self.route_length,
@@ -78,8 +564,223 @@ class AlgorithmResult:
             "iterations": self.iterations,
             "start_timestamp": self.start_timestamp.isoformat(),
             "additional_metrics": self.additional_metrics,
+            "metric_series": self.metric_series,
             "dataset_size": self.dataset_size,
             "dataset_name": self.dataset_name,
+            "convergence": self.convergence,
+            "instance_name": self.instance_name,
+            "code_version": self.code_version,
+            "experiment_id": self.experiment_id,
+            "tags": self.tags,
+            "content_hash": self.content_hash,
+            "known_optimum": self.known_optimum,
+            "is_closed": self.is_closed,
+            "schema_version": self.schema_version,
+        }
+        if self.coords is not None:
+            data["coords"] = [list(point) for point in self.coords]
+        return data
+
+    def to_report(self) -> str:
+        """
+        Zwraca czytelne, wielowierszowe podsumowanie wyniku (np. do wklejenia w e-mail):
+        algorytm, instancja, długość trasy, luka do optimum (jeśli znane), czas,
+        iteracje oraz posortowane parametry i metryki.
+        """
+        rows = [
+            ("algorytm", self.algorithm_name),
+            ("instancja", self.instance_name or self.dataset_name or "-"),
+            ("liczba miast", str(self.dataset_size)),
+            ("eksperyment", self.experiment_id or "-"),
+            ("tagi", ", ".join(self.tags) or "-"),
+            ("długość trasy", f"{self.route_length:.2f}"),
+            ("trasa", "zamknięta" if self.is_closed else "otwarta (bez powrotu)"),
+        ]
+        if self.known_optimum is not None:
+            rows.append(("optimum", f"{self.known_optimum:.2f}"))
+            gap = self.additional_metrics.get("optimality_gap")
+            if gap is not None:
+                rows.append(("luka do optimum", f"{gap:.2f}%"))
+        rows += [
+            ("czas wykonania", f"{self.execution_time_s:.3f} s"),
+            ("iteracje", str(self.iterations)),
+            ("start", self.start_timestamp.isoformat()),
+            ("wersja kodu", self.code_version or "-"),
+        ]
+
+        width = max(len(label) for label, _ in rows)
+        lines = [f"{label.ljust(width)}  {value}" for label, value in rows]
+        sections = [
+            ("Parametry", [(k, v) for k, v in sorted(self.parameters.items())]),
+            ("Metryki", [(k, f"{v:g}") for k, v in sorted(self.additional_metrics.items())]),
+        ]
+        for header, entries in sections:
+            if not entries:
+                continue
+            key_width = max(len(key) for key, _ in entries)
+            lines += ["", f"{header}:"]
+            lines += [f"  {key.ljust(key_width)}  {value}" for key, value in entries]
+        return "\n".join(lines) + "\n"
+
+    def diff(self, other: "AlgorithmResult") -> "ResultDiff":
+        """
+        Porównuje ten wynik z innym (np. uruchomieniem ze zmienionym parametrem).
+
+        Różnice liczone są jako other - self. Zwracane są tylko te klucze parametrów
+        i metryk, których wartości się różnią (brak klucza oznacza None).
+        """
+        parameter_changes = {
+            key: (self.parameters.get(key), other.parameters.get(key))
+            for key in sorted(set(self.parameters) | set(other.parameters))
+            if self.parameters.get(key) != other.parameters.get(key)
+        }
+        metric_changes = {
+            key: (self.additional_metrics.get(key), other.additional_metrics.get(key))
+            for key in sorted(set(self.additional_metrics) | set(other.additional_metrics))
+            if self.additional_metrics.get(key) != other.additional_metrics.get(key)
+        }
+        return ResultDiff(
+            route_length_delta=other.route_length - self.route_length,
+            execution_time_delta_s=other.execution_time_s - self.execution_time_s,
+            iterations_delta=other.iterations - self.iterations,
+            parameter_changes=parameter_changes,
+            metric_changes=metric_changes,
+        )
+
+    @staticmethod
+    def summarize(results: List["AlgorithmResult"]) -> "Summary":
+        """
+        Podsumowuje wiele uruchomień (statystyki długości trasy i czasu).
+
+        Odchylenie standardowe liczone jest wzorem próbkowym (n - 1);
+        dla pojedynczego wyniku wynosi 0.
+
+        Raises:
+            ValueError: Jeśli lista wyników jest pusta
+        """
+        if not results:
+            raise ValueError("Cannot summarize an empty list of results")
+
+        lengths = [r.route_length for r in results]
+        count_per_algorithm: Dict[str, int] = {}
+        for r in results:
+            count_per_algorithm[r.algorithm_name] = (
+                count_per_algorithm.get(r.algorithm_name, 0) + 1
+            )
+
+        return Summary(
+            count=len(results),
+            best_length=min(lengths),
+            worst_length=max(lengths),
+            mean_length=statistics.mean(lengths),
+            std_dev_length=statistics.stdev(lengths) if len(lengths) > 1 else 0.0,
+            mean_execution_time_s=statistics.mean(r.execution_time_s for r in results),
+            count_per_algorithm=count_per_algorithm,
+        )
+
+    @classmethod
+    def from_dict(cls, data: dict) -> "AlgorithmResult":
+        """
+        Tworzy AlgorithmResult ze słownika (odwrotność to_dict).
+
+        Słowniki w starszej wersji schematu są najpierw migrowane
+        (patrz migrate_result_dict).
+
+        Args:
+            data: Słownik odczytany z pliku JSON wyniku
+
+        Returns:
+            AlgorithmResult: Odtworzony wynik
+
+        Raises:
+            ValueError: Jeśli słownik nie odpowiada strukturze wyniku
+        """
+        data = migrate_result_dict(data)
+        try:
+            return cls(
+                algorithm_name=data["algorithm_name"],
+                parameters=dict(data.get("parameters", {})),
+                route_length=float(data.get("route_length", 0.0)),
+                route=list(data.get("route", [])),
+                execution_time_s=float(data.get("execution_time_s", 0.0)),
+                iterations=int(data.get("iterations", 0)),
+                start_timestamp=datetime.fromisoformat(data["start_timestamp"]),
+                additional_metrics=dict(data.get("additional_metrics", {})),
+                metric_series={
+                    key: [float(v) for v in values]
+                    for key, values in data.get("metric_series", {}).items()
+                },
+                dataset_size=int(data.get("dataset_size", 0)),
+                dataset_name=data.get("dataset_name", ""),
+                convergence=[float(v) for v in data.get("convergence", [])],
+                instance_name=data.get("instance_name"),
+                code_version=data.get("code_version"),
+                experiment_id=data.get("experiment_id"),
+                tags=[str(tag) for tag in data.get("tags", [])],
+                content_hash=data.get("content_hash"),
+                known_optimum=(
+                    float(data["known_optimum"]) if data.get("known_optimum") is not None else None
+                ),
+                coords=(
+                    [(float(x), float(y)) for x, y in data["coords"]]
+                    if data.get("coords") is not None
+                    else None
+                ),
+                is_closed=bool(data.get("is_closed", True)),
+                schema_version=data["schema_version"],
+            )
+        except (KeyError, TypeError, ValueError) as e:
+            raise ValueError(f"Invalid result structure: {e!r}")
+
+
+@dataclass
+class Summary:
+    """Zbiorcze statystyki wielu uruchomień algorytmów."""
+
+    count: int
+    best_length: float
+    worst_length: float
+    mean_length: float
+    std_dev_length: float
+    mean_execution_time_s: float
+    count_per_algorithm: Dict[str, int] = field(default_factory=dict)
+
+    def to_dict(self) -> dict:
+        """Konwertuje do słownika do serializacji JSON."""
+        return {
+            "count": self.count,
+            "best_length": self.best_length,
+            "worst_length": self.worst_length,
+            "mean_length": self.mean_length,
+            "std_dev_length": self.std_dev_length,
+            "mean_execution_time_s": self.mean_execution_time_s,
+            "count_per_algorithm": self.count_per_algorithm,
+        }
+
+
+@dataclass
+class ResultDiff:
+    """Różnice między dwoma wynikami (wartości drugiego minus pierwszego)."""
+
+    route_length_delta: float
+    execution_time_delta_s: float
+    iterations_delta: int
+    # Klucz -> (wartość w pierwszym wyniku, wartość w drugim wyniku)
+    parameter_changes: Dict[str, Tuple[Optional[str], Optional[str]]] = field(
+        default_factory=dict
+    )
+    metric_changes: Dict[str, Tuple[Optional[float], Optional[float]]] = field(
+        default_factory=dict
+    )
+
+    def to_dict(self) -> dict:
+        """Konwertuje do słownika do serializacji JSON."""
+        return {
+            "route_length_delta": self.route_length_delta,
+            "execution_time_delta_s": self.execution_time_delta_s,
+            "iterations_delta": self.iterations_delta,
+            "parameter_changes": {k: list(v) for k, v in self.parameter_changes.items()},
+            "metric_changes": {k: list(v) for k, v in self.metric_changes.items()},
         }
 
 
@@ -100,48 +801,450 @@ class ResultMonitor:
             project_root = Path(__file__).parent.parent.parent
             results_path = project_root / results_dir
         self.results_dir = str(results_path)
+        # Czy save_result zapisuje pliki .json.gz zamiast .json
+        self.compress = False
+        # Czy save_result zapisuje binarne pliki .bin (ma pierwszeństwo przed compress)
+        self.binary = False
+        # Czy przed zapisem sprawdzana jest spójność route i route_length
+        self.validate = True
+        # Czy pliki wyników zapisywane są z wcięciami (False - zwarty JSON)
+        self.pretty = True
+        # Czy zapisywane trasy są obracane tak, aby zaczynały się od miasta 0
+        self.normalize_routes = False
+        # Liczba buforowanych wyników, po której następuje zapis (None - zapis od razu)
+        self.buffer_size: Optional[int] = None
+        # Wyniki oczekujące na zapis: (zarezerwowana nazwa pliku, JSON) w kolejności zapisu
+        self._pending: List[Tuple[str, ResultPayload]] = []
+        # Maksymalna liczba plików wyników w katalogu (None - bez limitu)
+        self.max_files: Optional[int] = None
+
+    @classmethod
+    def with_dir(cls, results_dir: str) -> "ResultMonitor":
+        """
+        Tworzy ResultMonitor zapisujący wyniki we wskazanym katalogu.
+
+        Ścieżki względne (również zagnieżdżone, np. "out/experiment1") liczone są
+        względem katalogu głównego projektu; katalog tworzony jest przy pierwszym zapisie.
+        """
+        return cls(results_dir=str(results_dir))
+
+    def with_compression(self, enabled: bool = True) -> "ResultMonitor":
+        """
+        Włącza lub wyłącza kompresję gzip przy zapisie (save_result tworzy pliki .json.gz).
+        Odczyt obsługuje oba formaty niezależnie od tego ustawienia.
+        """
+        self.compress = enabled
+        return self
+
+    def with_binary(self, enabled: bool = True) -> "ResultMonitor":
+        """
+        Włącza lub wyłącza zapis binarny (save_result tworzy pliki .bin): ten sam
+        słownik co w pliku JSON, serializowany przez pickle - mniejsze pliki i szybszy
+        load_all przy dużych archiwach. Zapis jest bezstratny (znaczniki czasu
+        w formacie ISO z mikrosekundami i strefą, wszystkie słowniki). Odczyt
+        obsługuje wszystkie formaty niezależnie od tego ustawienia.
+        """
+        self.binary = enabled
+        return self
+
+    def with_pretty(self, enabled: bool = True) -> "ResultMonitor":
+        """
+        Przełącza zapis plików wyników między JSON z wcięciami (domyślnie)
+        a zwartym JSON w jednej linii (mniejsze pliki, szybszy zapis).
+        """
+        self.pretty = enabled
+        return self
+
+    def with_normalized_routes(self, enabled: bool = True) -> "ResultMonitor":
+        """
+        Włącza lub wyłącza obracanie zapisywanych tras tak, aby zaczynały się od miasta 0.
+        Kierunek i długość trasy pozostają bez zmian; wynik w pamięci nie jest modyfikowany.
+        """
+        self.normalize_routes = enabled
+        return self
+
+    def with_validation(self, enabled: bool = True) -> "ResultMonitor":
+        """
+        Włącza lub wyłącza sprawdzanie spójności wyniku przed zapisem
+        (domyślnie włączone, patrz _check_result).
+        """
+        self.validate = enabled
+        return self
+
+    def with_buffering(self, enabled: bool = True, batch_size: int = 100) -> "ResultMonitor":
+        """
+        Włącza lub wyłącza buforowany zapis: save_result (i save_result_delta) tylko
+        serializuje wynik i rezerwuje nazwę pliku, a pliki zapisywane są partiami po
+        batch_size wyników, przy flush() oraz przy zamknięciu (with / usunięcie obiektu).
+        Pliki zapisywane są w kolejności wywołań save_result.
+
+        Przydatne przy zapisie wyniku w każdym pokoleniu - pętla algorytmu nie czeka
+        na pojedyncze zapisy. Wyłączenie buforowania zapisuje oczekujące wyniki.
+
+        Raises:
+            ValueError: Jeśli batch_size < 1
+        """
+        if batch_size < 1:
+            raise ValueError("batch_size must be >= 1")
+        if not enabled:
+            self.flush()
+        self.buffer_size = batch_size if enabled else None
+        return self
+
+    def with_max_files(self, max_files: Optional[int]) -> "ResultMonitor":
+        """
+        Ogranicza liczbę plików wyników w katalogu (archiwum cykliczne do długich,
+        nienadzorowanych uruchomień): przed zapisem nowego wyniku (save_result,
+        save_result_delta, także przy flush) usuwane są najstarsze wyniki wg znacznika
+        czasu z nazwy, tak aby po zapisie było ich co najwyżej max_files. Liczone i usuwane
+        są tylko pliki wyników (.json, .json.gz i .bin); każde usunięcie jest wypisywane.
+        None wyłącza limit.
+
+        Uwaga: usunięcie wyniku bazowego uniemożliwia odczyt zależnych od niego
+        wyników różnicowych.
+
+        Raises:
+            ValueError: Jeśli max_files < 1
+        """
+        if max_files is not None and max_files < 1:
+            raise ValueError("max_files must be >= 1")
+        self.max_files = max_files
+        return self
+
+    def _rotate(self) -> None:
+        """Usuwa najstarsze wyniki, aby zmieścić nowy plik w limicie max_files."""
+        if self.max_files is None:
+            return
+        files = self.list_results()
+        for filename in files[: max(len(files) - self.max_files + 1, 0)]:
+            self.delete_result(filename)
+            print(f"Usunięto najstarszy wynik (limit {self.max_files} plików): {filename}")
+
+    def flush(self) -> List[str]:
+        """
+        Zapisuje wszystkie buforowane wyniki (w kolejności zapisu).
+
+        Returns:
+            List[str]: Nazwy zapisanych plików
+
+        Raises:
+            FileExistsError: Jeśli zarezerwowaną nazwę zajął w międzyczasie inny proces
+                             (ten i kolejne wyniki pozostają w buforze)
+            IOError: Jeśli plik nie może zostać zapisany
+        """
+        written = []
+        if self._pending:
+            Path(self.results_dir).mkdir(parents=True, exist_ok=True)
+        while self._pending:
+            filename, payload = self._pending[0]
+            self._rotate()
+            try:
+                with self._open_result(Path(self.results_dir) / filename, "x") as f:
+                    f.write(payload)
+            except FileExistsError:
+                raise FileExistsError(f"Reserved result file already exists: {filename}")
+            self._pending.pop(0)
+            written.append(filename)
+        return written
+
+    def __enter__(self) -> "ResultMonitor":
+        return self
+
+    def __exit__(self, *exc_info) -> None:
+        self.flush()
+
+    def __del__(self) -> None:
+        # Zapis pozostałych wyników przy usuwaniu obiektu (w miarę możliwości)
+        try:
+            self.flush()
+        except Exception:
+            pass
 
-    def save_result(self, result: AlgorithmResult) -> str:
+    def _check_result(self, result: AlgorithmResult) -> None:
         """
-        Zapisuje wynik algorytmu do pliku JSON.
+        Odrzuca oczywiście niespójne wyniki (np. gdy zapomniano wywołać set_result).
+
+        Raises:
+            ValueError: Jeśli route_length > 0 przy pustej trasie lub route_length == 0
+                        przy trasie z co najmniej dwoma miastami
+        """
+        if not self.validate:
+            return
+        if result.route_length < 0:
+            raise ValueError(
+                f"Inconsistent result: negative route_length {result.route_length}"
+            )
+        if result.route_length > 0 and not result.route:
+            raise ValueError(
+                f"Inconsistent result: route_length is {result.route_length} but route is empty"
+            )
+        if result.route_length == 0 and len(result.route) > 1:
+            raise ValueError(
+                f"Inconsistent result: route has {len(result.route)} cities "
+                "but route_length is 0 (was set_result called?)"
+            )
+
+    @staticmethod
+    def _is_result_filename(filename: str) -> bool:
+        """Sprawdza, czy nazwa pliku ma rozszerzenie pliku wyniku (.json, .json.gz, .bin)."""
+        return filename.endswith(RESULT_EXTENSIONS)
+
+    @staticmethod
+    def _open_result(filepath: Path, mode: str):
+        """
+        Otwiera plik wyniku w trybie tekstowym, przez gzip dla plików .gz,
+        a pliki .bin - w trybie binarnym.
+        """
+        if filepath.name.endswith(".gz"):
+            return gzip.open(filepath, mode + "t", encoding="utf-8")
+        if filepath.name.endswith(BINARY_RESULT_EXTENSION):
+            return open(filepath, mode + "b")
+        return open(filepath, mode, encoding="utf-8")
+
+    def _extension(self) -> str:
+        """Rozszerzenie plików tworzonych przez save_result w bieżącym trybie."""
+        if self.binary:
+            return BINARY_RESULT_EXTENSION
+        return COMPRESSED_RESULT_EXTENSION if self.compress else RESULT_EXTENSION
+
+    @staticmethod
+    def _check_filename(filename: str) -> None:
+        """Odrzuca nazwy plików wychodzące poza katalog wyników."""
+        if not filename or filename == "." or ".." in filename:
+            raise ValueError(f"Invalid result filename: {filename!r}")
+        if "/" in filename or "\\" in filename:
+            raise ValueError(f"Result filename must not contain path separators: {filename!r}")
+
+    def _to_dict(self, result: AlgorithmResult) -> dict:
+        """Konwertuje wynik do słownika do zapisu (z ewentualną normalizacją trasy)."""
+        data = result.to_dict()
+        if self.normalize_routes:
+            data["route"] = rotate_to_start(result.route)
+        return data
+
+    def _dumps(self, data: dict, binary: Optional[bool] = None) -> ResultPayload:
+        """
+        Serializuje słownik wyniku do JSON (z wcięciami lub zwarty) albo - przy binary
+        (domyślnie ustawienie with_binary) - do bajtów pliku .bin.
+        """
+        if self.binary if binary is None else binary:
+            return pickle.dumps(data, protocol=BINARY_PICKLE_PROTOCOL)
+        if self.pretty:
+            return json.dumps(data, indent=2, ensure_ascii=False)
+        return json.dumps(data, separators=(",", ":"), ensure_ascii=False)
+
+    def _serialize(
+        self, result: AlgorithmResult, binary: Optional[bool] = None
+    ) -> ResultPayload:
+        """Konwertuje wynik do słownika i serializuje (patrz _dumps)."""
+        return self._dumps(self._to_dict(result), binary)
+
+    def save_result(
+        self, result: AlgorithmResult, distance_matrix: Optional[DistanceMatrix] = None
+    ) -> str:
+        """
+        Zapisuje wynik algorytmu do pliku JSON (.json.gz przy kompresji, .bin w trybie
+        binarnym).
+
+        Args:
+            result: AlgorithmResult do zapisania
+            distance_matrix: Opcjonalna macierz odległości instancji - zapisywana razem
+                             z wynikiem (pole distance_matrix, a także distance_metric
+                             i distance_rounding, jeśli są ustawione), aby można było
+                             odtworzyć uruchomienie na identycznych danych (patrz
+                             load_instance).
+                             Jeśli wynik nie ma content_hash, jest on uzupełniany.
+
+        Jeśli plik o danej nazwie już istnieje (w dowolnym z obu formatów), do nazwy
+        dodawany jest sufiks _1, _2, ... - istniejące wyniki nigdy nie są nadpisywane.
+        Przy buforowaniu (with_buffering) plik powstaje dopiero przy flush().
+
+        Returns:
+            str: Nazwa pliku zapisanego (lub zarezerwowanego) wyniku (z ewentualnym sufiksem)
+
+        Raises:
+            ValueError: Jeśli wynik jest niespójny (przy włączonej walidacji)
+                        lub content_hash wyniku nie zgadza się z distance_matrix
+            IOError: Jeśli plik nie może zostać zapisany
+        """
+        self._check_result(result)
+        if distance_matrix is None:
+            return self._write_new(result, self._serialize(result))
+
+        if result.content_hash is None:
+            result.with_content_hash(distance_matrix)
+        elif not result.matches_instance(distance_matrix):
+            raise ValueError("Result content_hash does not match the given distance matrix")
+        data = self._to_dict(result)
+        data["distance_matrix"] = [list(row) for row in distance_matrix]
+        if distance_matrix.metric is not None:
+            data["distance_metric"] = distance_matrix.metric.value
+        if distance_matrix.rounding != RoundingMode.NONE:
+            data["distance_rounding"] = distance_matrix.rounding.value
+        return self._write_new(result, self._dumps(data))
+
+    def save_result_delta(self, result: AlgorithmResult, base_filename: str) -> str:
+        """
+        Zapisuje wynik różnicowo: zamiast pełnej trasy plik zawiera odwołanie
+        do wyniku bazowego (base_filename) i listę zamian pozycji (route_swaps),
+        które odtwarzają trasę z trasy bazowej. load_result odtwarza pełną trasę,
+        również przez łańcuch kolejnych wyników różnicowych.
+
+        Przydatne przy zapisie najlepszej trasy w każdym pokoleniu - kolejne trasy
+        różnią się zwykle kilkoma zamianami. Usunięcie wyniku bazowego uniemożliwia
+        odczyt zależnych od niego wyników.
 
         Args:
             result: AlgorithmResult do zapisania
+            base_filename: Nazwa pliku wyniku bazowego w katalogu wyników
 
         Returns:
             str: Nazwa pliku zapisanego wyniku
 
         Raises:
+            FileNotFoundError: Jeśli plik wyniku bazowego nie istnieje
+            ValueError: Jeśli wynik jest niespójny, nazwa pliku bazowego jest niepoprawna
+                        lub trasy nie są permutacjami tych samych miast
             IOError: Jeśli plik nie może zostać zapisany
         """
-        # Upewnij się, że katalog wyników istnieje
-        Path(self.results_dir).mkdir(parents=True, exist_ok=True)
+        self._check_filename(base_filename)
+        self._check_result(result)
+        base_route = self.load_result(base_filename).route
+
+        data = self._to_dict(result)
+        data["route_swaps"] = [list(swap) for swap in route_swaps(base_route, data["route"])]
+        data["route"] = []
+        data["base_filename"] = base_filename
+        return self._write_new(result, self._dumps(data))
 
-        # Utwórz nazwę pliku z nazwą algorytmu, rozmiarem zbioru danych i znacznikiem czasu
+    @staticmethod
+    def _result_stem(result: AlgorithmResult) -> str:
+        """Nazwa pliku wyniku bez rozszerzenia i sufiksu kolizji."""
+        # Nazwa pliku z nazwą algorytmu, rozmiarem zbioru danych i znacznikiem czasu
         timestamp = result.start_timestamp.strftime("%Y%m%d_%H%M%S_%f")[
             :-3
         ]  # milisekundy
-        filename = (
-            f"{result.algorithm_name}_{result.dataset_size}cities_{timestamp}.json"
-        )
-        filepath = Path(self.results_dir) / filename
+        return f"{result.algorithm_name}_{result.dataset_size}cities_{timestamp}"
 
-        # Konwertuj do słownika i serializuj do JSON
-        result_dict = result.to_dict()
-        json_str = json.dumps(result_dict, indent=2, ensure_ascii=False)
+    def _reserve_filename(self, result: AlgorithmResult) -> str:
+        """Wybiera wolną nazwę pliku dla buforowanego wyniku (również wśród oczekujących)."""
+        stem = self._result_stem(result)
+        extension = self._extension()
+        pending = {filename for filename, _ in self._pending}
+        suffix = 0
+        while True:
+            base = stem if suffix == 0 else f"{stem}_{suffix}"
+            suffix += 1
+            names = [f"{base}{ext}" for ext in RESULT_EXTENSIONS]
+            if any(
+                name in pending or (Path(self.results_dir) / name).exists() for name in names
+            ):
+                continue
+            return f"{base}{extension}"
 
-        # Zapisz do pliku
-        with open(filepath, "w", encoding="utf-8") as f:
-            f.write(json_str)
+    def _write_new(self, result: AlgorithmResult, payload: ResultPayload) -> str:
+        """Zapisuje JSON (lub bajty .bin) wyniku do nowego pliku o nazwie z wyniku."""
+        if self.buffer_size is not None:
+            filename = self._reserve_filename(result)
+            self._pending.append((filename, payload))
+            if len(self._pending) >= self.buffer_size:
+                self.flush()
+            return filename
+
+        # Upewnij się, że katalog wyników istnieje
+        Path(self.results_dir).mkdir(parents=True, exist_ok=True)
+        self._rotate()
+
+        stem = self._result_stem(result)
+        extension = self._extension()
+
+        # Zapisz do pliku - tryb "x" nie nadpisuje istniejącego pliku,
+        # więc przy kolizji próbowana jest kolejna nazwa
+        suffix = 0
+        while True:
+            base = stem if suffix == 0 else f"{stem}_{suffix}"
+            suffix += 1
+            # Ta sama nazwa w innym formacie również jest kolizją
+            if any(
+                (Path(self.results_dir) / f"{base}{ext}").exists()
+                for ext in RESULT_EXTENSIONS
+                if ext != extension
+            ):
+                continue
+            filename = f"{base}{extension}"
+            try:
+                with self._open_result(Path(self.results_dir) / filename, "x") as f:
+                    f.write(payload)
+                return filename
+            except FileExistsError:
+                continue
+
+    def save_result_as(self, result: AlgorithmResult, filename: str) -> str:
+        """
+        Zapisuje wynik algorytmu do pliku JSON o podanej nazwie (np. "best.json").
+        Nazwy kończące się na .gz zapisywane są z kompresją gzip, a na .bin - binarnie
+        (jak przy with_binary).
+        Istniejący plik o tej nazwie jest nadpisywany.
+
+        Args:
+            result: AlgorithmResult do zapisania
+            filename: Nazwa pliku w katalogu wyników (bez ścieżki)
+
+        Returns:
+            str: Nazwa pliku zapisanego wyniku
+
+        Raises:
+            ValueError: Jeśli nazwa pliku zawiera separatory ścieżki lub ".."
+                        albo wynik jest niespójny (przy włączonej walidacji)
+            IOError: Jeśli plik nie może zostać zapisany
+        """
+        self._check_filename(filename)
+        self._check_result(result)
+        Path(self.results_dir).mkdir(parents=True, exist_ok=True)
+
+        with self._open_result(Path(self.results_dir) / filename, "w") as f:
+            f.write(self._serialize(result, filename.endswith(BINARY_RESULT_EXTENSION)))
 
         return filename
 
+    def save_report(self, result: AlgorithmResult, filename: str) -> str:
+        """
+        Zapisuje tekstowy raport wyniku (AlgorithmResult.to_report) obok pliku JSON,
+        np. dla "ga_20240101_120000.json" jako "ga_20240101_120000.txt".
+        Istniejący raport o tej nazwie jest nadpisywany.
+
+        Args:
+            result: Wynik, którego dotyczy raport
+            filename: Nazwa pliku wyniku (zwrócona przez save_result)
+
+        Returns:
+            str: Nazwa pliku raportu
+
+        Raises:
+            ValueError: Jeśli nazwa pliku zawiera separatory ścieżki lub ".."
+            IOError: Jeśli plik nie może zostać zapisany
+        """
+        self._check_filename(filename)
+        stem = filename
+        for extension in RESULT_EXTENSIONS:
+            if stem.endswith(extension):
+                stem = stem[: -len(extension)]
+                break
+        report_filename = stem + REPORT_EXTENSION
+        Path(self.results_dir).mkdir(parents=True, exist_ok=True)
+
+        with open(Path(self.results_dir) / report_filename, "w", encoding="utf-8") as f:
+            f.write(result.to_report())
+        return report_filename
+
     def list_results(self) -> List[str]:
         """
-        Wyświetla listę wszystkich plików wyników w katalogu wyników.
+        Wyświetla listę wszystkich plików wyników (.json, .json.gz i .bin) w katalogu wyników.
 
         Returns:
-            List[str]: Lista nazw plików wyników, posortowana alfabetycznie
+            List[str]: Lista nazw plików wyników (z rozszerzeniem, tak jak przyjmuje je
+            load_result), posortowana chronologicznie wg znacznika czasu z nazwy
+            (patrz result_sort_key)
         """
         results_path = Path(self.results_dir)
 
@@ -150,8 +1253,579 @@ class ResultMonitor:
 
         files = []
         for entry in results_path.iterdir():
-            if entry.is_file() and entry.suffix == ".json":
+            if entry.is_file() and self._is_result_filename(entry.name):
                 files.append(entry.name)
 
-        files.sort()
+        files.sort(key=result_sort_key)
         return files
+
+    def list_results_filtered(
+        self,
+        algorithm: Optional[str] = None,
+        after: Optional[datetime] = None,
+        before: Optional[datetime] = None,
+    ) -> List[str]:
+        """
+        Wyświetla listę plików wyników pasujących do podanych filtrów.
+
+        Nazwa algorytmu i znacznik czasu odczytywane są z nazwy pliku; jeśli nazwa
+        nie pasuje do wzorca, plik jest wczytywany i używany jest jego start_timestamp.
+
+        Args:
+            algorithm: Nazwa algorytmu (np. "ihc"); None oznacza dowolny algorytm
+            after: Tylko wyniki rozpoczęte w tej chwili lub później
+            before: Tylko wyniki rozpoczęte przed tą chwilą
+                    (after i before bez strefy czasowej traktowane są jako UTC,
+                    jak znaczniki czasu w nazwach plików)
+
+        Returns:
+            List[str]: Lista nazw plików wyników, w kolejności jak w list_results
+
+        Raises:
+            ValueError: Jeśli plik spoza wzorca nazw jest uszkodzony
+        """
+        if after is not None and after.tzinfo is None:
+            after = after.replace(tzinfo=timezone.utc)
+        if before is not None and before.tzinfo is None:
+            before = before.replace(tzinfo=timezone.utc)
+
+        filtered = []
+        for filename in self.list_results():
+            parsed = parse_result_filename(filename)
+            if parsed is None:
+                result = self.load_result(filename)
+                parsed = (result.algorithm_name, result.start_timestamp)
+            algorithm_name, timestamp = parsed
+
+            if algorithm is not None and algorithm_name != algorithm:
+                continue
+            if after is not None and timestamp < after:
+                continue
+            if before is not None and timestamp >= before:
+                continue
+            filtered.append(filename)
+        return filtered
+
+    def load_result(self, filename: str) -> AlgorithmResult:
+        """
+        Wczytuje zapisany wynik algorytmu z pliku (.json, .json.gz lub .bin).
+        Dla wyników zapisanych różnicowo (save_result_delta) odtwarzana jest pełna trasa.
+
+        Args:
+            filename: Nazwa pliku wyniku (względem katalogu wyników)
+
+        Returns:
+            AlgorithmResult: Odczytany wynik
+
+        Raises:
+            FileNotFoundError: Jeśli plik wyniku (lub wyniku bazowego) nie istnieje
+            ValueError: Jeśli plik nie zawiera poprawnego JSON lub struktury wyniku
+                        albo łańcuch wyników różnicowych jest uszkodzony lub cykliczny
+        """
+        data = self._read_dict(filename)
+        if isinstance(data, dict) and "base_filename" in data:
+            data = dict(data, route=self._resolve_delta_route(filename, data))
+
+        try:
+            return AlgorithmResult.from_dict(data)
+        except ValueError as e:
+            raise ValueError(f"Failed to load result file {filename}: {e}")
+
+    def load_instance(self, filename: str) -> Optional[DistanceMatrix]:
+        """
+        Wczytuje macierz odległości zapisaną razem z wynikiem (save_result z distance_matrix),
+        razem z metryką i trybem zaokrąglania, z którymi ją zbudowano
+        (DistanceMatrix.metric, DistanceMatrix.rounding).
+
+        Returns:
+            Optional[DistanceMatrix]: Zapisana macierz lub None, jeśli wynik jej nie zawiera
+
+        Raises:
+            FileNotFoundError: Jeśli plik wyniku nie istnieje
+            ValueError: Jeśli plik jest niepoprawny albo skrót zapisanej macierzy
+                        nie zgadza się z content_hash wyniku
+        """
+        data = self._read_dict(filename)
+        if not isinstance(data, dict) or data.get("distance_matrix") is None:
+            return None
+        try:
+            matrix = DistanceMatrix([[float(v) for v in row] for row in data["distance_matrix"]])
+        except (TypeError, ValueError) as e:
+            raise ValueError(f"Invalid distance_matrix in {filename}: {e!r}")
+        content_hash = data.get("content_hash")
+        if content_hash is not None and content_hash != matrix.content_hash():
+            raise ValueError(f"Stored distance_matrix in {filename} does not match content_hash")
+        if data.get("distance_metric") is not None:
+            try:
+                matrix.metric = DistanceMetric(data["distance_metric"])
+            except ValueError:
+                raise ValueError(
+                    f"Unknown distance_metric in {filename}: {data['distance_metric']!r}"
+                )
+        if data.get("distance_rounding") is not None:
+            try:
+                matrix.rounding = RoundingMode(data["distance_rounding"])
+            except ValueError:
+                raise ValueError(
+                    f"Unknown distance_rounding in {filename}: {data['distance_rounding']!r}"
+                )
+        return matrix
+
+    def _resolve_delta_route(self, filename: str, data: dict) -> List[int]:
+        """Odtwarza trasę wyniku różnicowego, przechodząc łańcuch wyników bazowych."""
+        chain = [filename]
+        pending_swaps = []
+        current = data
+        while isinstance(current, dict) and "base_filename" in current:
+            base = current["base_filename"]
+            if not isinstance(base, str):
+                raise ValueError(f"Invalid base_filename in {chain[-1]}: {base!r}")
+            self._check_filename(base)
+            if base in chain:
+                raise ValueError(f"Cyclic delta chain: {' -> '.join(chain + [base])}")
+            pending_swaps.append(current.get("route_swaps", []))
+            chain.append(base)
+            current = self._read_dict(base)
+
+        if not isinstance(current, dict):
+            raise ValueError(f"Failed to load result file {chain[-1]}: not a JSON object")
+        route = list(current.get("route", []))
+        try:
+            for swaps in reversed(pending_swaps):
+                route = apply_swaps(route, [(int(i), int(j)) for i, j in swaps])
+        except (TypeError, ValueError) as e:
+            raise ValueError(f"Failed to resolve delta chain of {filename}: {e}")
+        return route
+
+    def _read_dict(self, filename: str) -> dict:
+        """Wczytuje i parsuje plik wyniku (bez interpretacji struktury)."""
+        if any(pending == filename for pending, _ in self._pending):
+            # Wynik jeszcze w buforze - zapis oczekujących, aby odczyt był spójny
+            self.flush()
+        filepath = Path(self.results_dir) / filename
+
+        try:
+            with self._open_result(filepath, "r") as f:
+                content = f.read()
+        except FileNotFoundError:
+            raise FileNotFoundError(f"Result file not found: {filepath}")
+        except (OSError, EOFError) as e:
+            raise ValueError(f"Failed to decompress result file {filename}: {e}")
+
+        if isinstance(content, bytes):
+            try:
+                return _ResultUnpickler(io.BytesIO(content)).load()
+            except Exception as e:
+                raise ValueError(f"Failed to parse binary result file {filename}: {e!r}")
+        try:
+            return json.loads(content)
+        except json.JSONDecodeError as e:
+            raise ValueError(f"Failed to parse result file {filename} as JSON: {e}")
+
+    def load_all(self, skip_invalid: bool = False) -> List[AlgorithmResult]:
+        """
+        Wczytuje wszystkie zapisane wyniki z katalogu wyników.
+
+        Args:
+            skip_invalid: Jeśli True, pliki, których nie da się odczytać, są pomijane
+                          (z komunikatem), zamiast przerywać całe wczytywanie
+
+        Returns:
+            List[AlgorithmResult]: Wyniki w kolejności zwracanej przez list_results
+
+        Raises:
+            FileNotFoundError: Jeśli plik zniknął w trakcie wczytywania (gdy skip_invalid=False)
+            ValueError: Jeśli któryś plik jest uszkodzony (gdy skip_invalid=False)
+        """
+        results = []
+        for filename in self.list_results():
+            try:
+                results.append(self.load_result(filename))
+            except (FileNotFoundError, ValueError) as e:
+                if not skip_invalid:
+                    raise
+                print(f"Pominięto plik wyniku {filename}: {e}")
+        return results
+
+    def check_integrity(self, repair: bool = False) -> List[Tuple[str, str]]:
+        """
+        Próbuje wczytać każdy plik wyniku i zwraca listę plików, których się nie da
+        (np. JSON ucięty po awarii w trakcie zapisu). Błędy nie przerywają sprawdzania.
+
+        Args:
+            repair: Jeśli True, uszkodzone pliki przenoszone są do podkatalogu corrupt/
+                    katalogu wyników (nie są wtedy widoczne dla list_results i load_all);
+                    istniejący tam plik o tej samej nazwie nie jest nadpisywany
+
+        Returns:
+            List[Tuple[str, str]]: Pary (nazwa_pliku, komunikat_błędu), w kolejności
+            jak w list_results
+
+        Raises:
+            IOError: Jeśli uszkodzonego pliku nie da się przenieść (gdy repair=True)
+        """
+        broken = []
+        for filename in self.list_results():
+            try:
+                self.load_result(filename)
+            except (FileNotFoundError, ValueError) as e:
+                broken.append((filename, str(e)))
+
+        if repair and broken:
+            corrupt_dir = Path(self.results_dir) / CORRUPT_DIRNAME
+            corrupt_dir.mkdir(exist_ok=True)
+            for filename, _ in broken:
+                target = corrupt_dir / filename
+                copy_no = 1
+                while target.exists():
+                    target = corrupt_dir / f"{filename}.{copy_no}"
+                    copy_no += 1
+                (Path(self.results_dir) / filename).rename(target)
+        return broken
+
+    def audit_lengths(
+        self,
+        distance_matrix: DistanceMatrix,
+        relative_tolerance: float = DEFAULT_LENGTH_TOLERANCE,
+    ) -> Tuple[List[Tuple[str, str]], int]:
+        """
+        Przelicza długość trasy każdego wyniku uzyskanego na podanej instancji
+        i zwraca wyniki, których zapisana route_length się z nią nie zgadza
+        (verify_length) lub których trasa nie jest permutacją miast instancji.
+
+        Wyniki innych instancji (inny content_hash albo - przy braku skrótu - inna
+        liczba miast) oraz pliki, których nie da się wczytać (patrz check_integrity),
+        są pomijane.
+
+        Returns:
+            Tuple[List[Tuple[str, str]], int]: Pary (nazwa_pliku, opis_niezgodności)
+            w kolejności jak w list_results oraz liczba sprawdzonych wyników
+        """
+        mismatches = []
+        audited = 0
+        for filename in self.list_results():
+            try:
+                result = self.load_result(filename)
+            except (FileNotFoundError, ValueError):
+                continue
+            if result.content_hash is not None:
+                if not result.matches_instance(distance_matrix):
+                    continue
+            elif len(result.route) != len(distance_matrix):
+                continue
+
+            audited += 1
+            try:
+                validate_route(result.route, len(distance_matrix))
+            except RouteError as e:
+                mismatches.append((filename, f"invalid route: {e}"))
+                continue
+            if not result.verify_length(distance_matrix, relative_tolerance=relative_tolerance):
+                mismatches.append(
+                    (
+                        filename,
+                        f"stored route_length {result.route_length:.6f}, "
+                        f"computed {result.compute_length(distance_matrix):.6f}",
+                    )
+                )
+        return mismatches, audited
+
+    def watch(
+        self,
+        callback: Callable[[AlgorithmResult], None],
+        interval_s: float = 1.0,
+        include_existing: bool = False,
+        should_stop: Optional[Callable[[], bool]] = None,
+    ) -> None:
+        """
+        Obserwuje katalog wyników i wywołuje callback dla każdego nowego pliku wyniku.
+
+        Katalog sprawdzany jest co interval_s sekund; każdy plik przekazywany jest
+        do callback najwyżej raz. Pliki, których nie da się jeszcze odczytać
+        (np. w trakcie zapisu), są ponawiane przy kolejnym sprawdzeniu.
+
+        Args:
+            callback: Funkcja wywoływana z każdym nowym wynikiem
+            interval_s: Odstęp między kolejnymi sprawdzeniami katalogu
+            include_existing: Jeśli True, również wyniki istniejące przed wywołaniem
+            should_stop: Funkcja sprawdzana po każdym przebiegu; obserwacja kończy się,
+                         gdy zwróci True (None - do przerwania, np. Ctrl+C)
+
+        Raises:
+            ValueError: Jeśli interval_s <= 0
+        """
+        if interval_s <= 0:
+            raise ValueError("interval_s must be > 0")
+
+        seen = set() if include_existing else set(self.list_results())
+        while True:
+            for filename in self.list_results():
+                if filename in seen:
+                    continue
+                try:
+                    result = self.load_result(filename)
+                except (FileNotFoundError, ValueError):
+                    continue
+                seen.add(filename)
+                callback(result)
+
+            if should_stop is not None and should_stop():
+                return
+            time.sleep(interval_s)
+
+    def list_by_experiment(self, experiment_id: str) -> List[AlgorithmResult]:
+        """
+        Wczytuje wszystkie wyniki należące do podanego eksperymentu.
+
+        Returns:
+            List[AlgorithmResult]: Wyniki z experiment_id równym podanemu,
+            w kolejności jak w list_results
+
+        Raises:
+            ValueError: Jeśli któryś z plików wyników jest uszkodzony
+        """
+        return [r for r in self.load_all() if r.experiment_id == experiment_id]
+
+    def count_for_experiment(self, experiment_id: str) -> int:
+        """
+        Liczy poprawne (dające się wczytać) wyniki podanego eksperymentu, np. aby po
+        przerwaniu serii uruchomień dokończyć tylko brakujące (patrz
+        utils.benchmark.resume_experiment). Uszkodzone pliki są pomijane, a wyniki
+        czekające w buforze (with_buffering) są najpierw zapisywane.
+
+        Returns:
+            int: Liczba wyników z experiment_id równym podanemu
+        """
+        self.flush()
+        return sum(1 for r in self.load_all(skip_invalid=True) if r.experiment_id == experiment_id)
+
+    def list_by_tag(self, tag: str) -> List[AlgorithmResult]:
+        """
+        Wczytuje wszystkie wyniki oznaczone podaną etykietą (porównanie dokładne,
+        z rozróżnianiem wielkości liter).
+
+        Returns:
+            List[AlgorithmResult]: Wyniki, których tags zawiera tag, w kolejności
+            jak w list_results
+
+        Raises:
+            ValueError: Jeśli któryś z plików wyników jest uszkodzony
+        """
+        return [r for r in self.load_all() if tag in r.tags]
+
+    def export_csv(self, output: str, delimiter: str = ";", decimal: str = ",") -> None:
+        """
+        Eksportuje wszystkie zapisane wyniki do pliku CSV (jeden wiersz na uruchomienie).
+
+        Kolumny: algorithm_name, dataset_name, route_length, execution_time_s, iterations,
+        start_timestamp oraz po jednej kolumnie dla każdej metryki z additional_metrics.
+        Brakujące metryki pozostawiane są puste. Domyślne separatory odpowiadają
+        plikom w katalogu dane/ (format Excela z polskimi ustawieniami).
+
+        Args:
+            output: Ścieżka do pliku CSV
+            delimiter: Separator kolumn
+            decimal: Separator dziesiętny dla wartości liczbowych
+
+        Raises:
+            ValueError: Jeśli któryś z plików wyników jest uszkodzony
+            IOError: Jeśli plik CSV nie może zostać zapisany
+        """
+        results = self.load_all()
+
+        metric_keys = sorted({key for r in results for key in r.additional_metrics})
+        header = [
+            "algorithm_name",
+            "dataset_name",
+            "route_length",
+            "execution_time_s",
+            "iterations",
+            "start_timestamp",
+        ] + metric_keys
+
+        def fmt(value: float) -> str:
+            return str(value).replace(".", decimal)
+
+        with open(output, "w", encoding="utf-8-sig", newline="") as f:
+            writer = csv.writer(f, delimiter=delimiter)
+            writer.writerow(header)
+            for r in results:
+                row = [
+                    r.algorithm_name,
+                    r.dataset_name,
+                    fmt(r.route_length),
+                    fmt(r.execution_time_s),
+                    str(r.iterations),
+                    r.start_timestamp.isoformat(),
+                ]
+                for key in metric_keys:
+                    value = r.additional_metrics.get(key)
+                    row.append(fmt(value) if value is not None else "")
+                writer.writerow(row)
+
+    def delete_result(self, filename: str) -> None:
+        """
+        Usuwa pojedynczy plik wyniku z katalogu wyników.
+
+        Args:
+            filename: Nazwa pliku wyniku (bez ścieżki)
+
+        Raises:
+            ValueError: Jeśli nazwa pliku zawiera separatory ścieżki lub ".."
+            FileNotFoundError: Jeśli plik wyniku nie istnieje
+        """
+        self._check_filename(filename)
+        filepath = Path(self.results_dir) / filename
+        try:
+            filepath.unlink()
+        except FileNotFoundError:
+            raise FileNotFoundError(f"Result file not found: {filepath}")
+
+    def clear(self) -> int:
+        """
+        Usuwa wszystkie pliki wyników (.json, .json.gz i .bin) z katalogu wyników.
+        Pozostałe pliki nie są modyfikowane.
+
+        Returns:
+            int: Liczba usuniętych plików
+        """
+        removed = 0
+        for filename in self.list_results():
+            self.delete_result(filename)
+            removed += 1
+        return removed
+
+    def prune(self, keep_top_k: int) -> int:
+        """
+        Dla każdego algorytmu (algorithm_name) zostawia keep_top_k wyników o najmniejszej
+        route_length i usuwa pozostałe. Remisy rozstrzyga kolejność uruchomień
+        (result_sort_key), więc ponowne wywołanie niczego już nie usuwa.
+
+        Usuwane są tylko pliki wyników (.json, .json.gz i .bin). Pliki, których nie da się
+        odczytać, oraz wyniki bazowe, od których zależą zachowane wyniki różnicowe
+        (save_result_delta), nie są usuwane.
+
+        Args:
+            keep_top_k: Liczba zachowywanych wyników na algorytm
+
+        Returns:
+            int: Liczba usuniętych plików
+
+        Raises:
+            ValueError: Jeśli keep_top_k < 0
+        """
+        if keep_top_k < 0:
+            raise ValueError("keep_top_k must be >= 0")
+
+        groups: Dict[str, List[Tuple[float, tuple, str]]] = {}
+        for filename in self.list_results():
+            try:
+                result = self.load_result(filename)
+            except (FileNotFoundError, ValueError):
+                continue
+            groups.setdefault(result.algorithm_name, []).append(
+                (result.route_length, result_sort_key(filename), filename)
+            )
+
+        kept: List[str] = []
+        candidates: List[str] = []
+        for entries in groups.values():
+            entries.sort()
+            kept += [filename for _, _, filename in entries[:keep_top_k]]
+            candidates += [filename for _, _, filename in entries[keep_top_k:]]
+
+        protected = set()
+        for filename in kept:
+            data = self._read_dict(filename)
+            while isinstance(data, dict) and isinstance(data.get("base_filename"), str):
+                base = data["base_filename"]
+                if base in protected:
+                    break
+                protected.add(base)
+                data = self._read_dict(base)
+
+        removed = 0
+        for filename in candidates:
+            if filename not in protected:
+                self.delete_result(filename)
+                removed += 1
+        return removed
+
+    def best_result(self, algorithm: Optional[str] = None) -> Optional[AlgorithmResult]:
+        """
+        Zwraca wynik z najkrótszą trasą (przy remisie - z krótszym czasem wykonania).
+
+        Args:
+            algorithm: Nazwa algorytmu do uwzględnienia; None oznacza wszystkie algorytmy
+
+        Returns:
+            Optional[AlgorithmResult]: Najlepszy wynik lub None, jeśli brak wyników
+
+        Raises:
+            ValueError: Jeśli któryś z plików wyników jest uszkodzony
+        """
+        results = [
+            self.load_result(filename)
+            for filename in self.list_results_filtered(algorithm=algorithm)
+        ]
+        if not results:
+            return None
+        return min(results, key=lambda r: (r.route_length, r.execution_time_s))
+
+    def append_jsonl(self, result: AlgorithmResult) -> None:
+        """
+        Dopisuje wynik jako jedną linię JSON do pliku results.jsonl w katalogu wyników.
+
+        Args:
+            result: AlgorithmResult do zapisania
+
+        Raises:
+            ValueError: Jeśli wynik jest niespójny (przy włączonej walidacji)
+            IOError: Jeśli plik nie może zostać zapisany
+        """
+        self._check_result(result)
+        Path(self.results_dir).mkdir(parents=True, exist_ok=True)
+        line = json.dumps(self._to_dict(result), ensure_ascii=False)
+        with open(Path(self.results_dir) / JSONL_FILENAME, "a", encoding="utf-8") as f:
+            f.write(line + "\n")
+
+    def load_jsonl(self) -> List[AlgorithmResult]:
+        """
+        Wczytuje wszystkie wyniki z pliku results.jsonl (puste linie są pomijane).
+
+        Returns:
+            List[AlgorithmResult]: Wyniki w kolejności dopisywania (pusta lista, jeśli plik nie istnieje)
+
+        Raises:
+            ValueError: Jeśli któraś linia nie zawiera poprawnego wyniku
+        """
+        filepath = Path(self.results_dir) / JSONL_FILENAME
+        if not filepath.exists():
+            return []
+
+        results = []
+        with open(filepath, "r", encoding="utf-8") as f:
+            for line_no, line in enumerate(f, start=1):
+                if not line.strip():
+                    continue
+                try:
+                    results.append(AlgorithmResult.from_dict(json.loads(line)))
+                except ValueError as e:
+                    raise ValueError(f"Failed to parse {JSONL_FILENAME} line {line_no}: {e}")
+        return results
+
+    def aggregate_by_algorithm(self) -> Dict[str, Summary]:
+        """
+        Wczytuje wszystkie wyniki i liczy podsumowanie osobno dla każdego algorytmu.
+
+        Returns:
+            Dict[str, Summary]: Podsumowanie (min/max/średnia/liczba, średni czas) dla
+            każdej nazwy algorytmu
+
+        Raises:
+            ValueError: Jeśli któryś z plików wyników jest uszkodzony
+        """
+        buckets: Dict[str, List[AlgorithmResult]] = {}
+        for result in self.load_all():
+            buckets.setdefault(result.algorithm_name, []).append(result)
+        return {name: AlgorithmResult.summarize(results) for name, results in buckets.items()}
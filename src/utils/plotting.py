@@ -0,0 +1,278 @@
+"""
+Moduł rysowania tras TSP i krzywych zbieżności do plików SVG oraz eksportu
+współrzędnych tras.
+
+Nie wymaga zewnętrznych bibliotek - SVG budowane jest jako tekst.
+"""
+
+import csv
+from pathlib import Path
+from typing import List, Optional, Sequence, Tuple
+
+from utils.data_types import Tour
+from utils.monitoring import AlgorithmResult
+
+# Typ dla współrzędnych miast (x, y)
+Coords = Sequence[Tuple[float, float]]
+
+SVG_SIZE = 800
+SVG_MARGIN = 20
+NODE_RADIUS = 3
+
+# Minimalna liczba cyfr numeru klatki w nazwach plików export_route_frames
+FRAME_NUMBER_DIGITS = 4
+
+# Wymiary wykresu zbieżności i kolory kolejnych serii
+CHART_WIDTH = 800
+CHART_HEIGHT = 500
+CHART_MARGIN = 60
+SERIES_COLORS = (
+    "steelblue", "crimson", "darkorange", "seagreen", "purple", "saddlebrown", "teal", "gray"
+)
+
+
+def _check_route_coords(route: Tour, coords: Coords) -> None:
+    """Sprawdza, czy każdy indeks trasy ma odpowiadające mu współrzędne."""
+    for city in route:
+        if city < 0 or city >= len(coords):
+            raise ValueError(
+                f"Route city index {city} has no coordinates (got {len(coords)} coords)"
+            )
+
+
+def _result_coords(result: AlgorithmResult, coords: Optional[Coords]) -> Coords:
+    """Zwraca podane współrzędne lub zapisane w wyniku (AlgorithmResult.coords)."""
+    if coords is not None:
+        return coords
+    if result.coords is None:
+        raise ValueError("No coordinates given and the result does not carry coords")
+    return result.coords
+
+
+def _project(coords: Coords) -> List[Tuple[float, float]]:
+    """Przeskalowuje współrzędne do obszaru rysunku (oś y skierowana w górę)."""
+    xs = [x for x, _ in coords]
+    ys = [y for _, y in coords]
+    min_x, max_x = min(xs), max(xs)
+    min_y, max_y = min(ys), max(ys)
+    span = max(max_x - min_x, max_y - min_y) or 1.0
+    scale = (SVG_SIZE - 2 * SVG_MARGIN) / span
+    return [
+        (SVG_MARGIN + (x - min_x) * scale, SVG_SIZE - SVG_MARGIN - (y - min_y) * scale)
+        for x, y in coords
+    ]
+
+
+def route_svg(route: Tour, coords: Coords, title: str = "") -> str:
+    """
+    Buduje dokument SVG z trasą: zamknięta łamana w kolejności trasy i kółka w miastach.
+
+    Raises:
+        ValueError: Jeśli indeks miasta w trasie nie ma współrzędnych
+    """
+    _check_route_coords(route, coords)
+
+    lines = [
+        f'<svg xmlns="http://www.w3.org/2000/svg" width="{SVG_SIZE}" height="{SVG_SIZE}" '
+        f'viewBox="0 0 {SVG_SIZE} {SVG_SIZE}">',
+        '<rect width="100%" height="100%" fill="white"/>',
+    ]
+    if title:
+        lines.append(f'<text x="{SVG_MARGIN}" y="{SVG_MARGIN - 5}" font-size="12">{title}</text>')
+
+    if coords:
+        points = _project(coords)
+        if route:
+            path = [points[city] for city in route] + [points[route[0]]]
+            path_str = " ".join(f"{x:.2f},{y:.2f}" for x, y in path)
+            lines.append(
+                f'<polyline points="{path_str}" fill="none" stroke="steelblue" stroke-width="1.5"/>'
+            )
+        for x, y in points:
+            lines.append(f'<circle cx="{x:.2f}" cy="{y:.2f}" r="{NODE_RADIUS}" fill="crimson"/>')
+
+    lines.append("</svg>")
+    return "\n".join(lines) + "\n"
+
+
+def plot_route(result: AlgorithmResult, coords: Optional[Coords], output: str) -> None:
+    """
+    Zapisuje trasę z wyniku jako plik SVG.
+
+    Args:
+        result: Wynik algorytmu z trasą do narysowania
+        coords: Współrzędne miast (indeks = numer miasta); None - współrzędne
+                zapisane w wyniku (with_coords)
+        output: Ścieżka do pliku SVG
+
+    Raises:
+        ValueError: Jeśli indeks miasta w trasie nie ma współrzędnych lub nie podano
+                    współrzędnych, a wynik ich nie zawiera
+        IOError: Jeśli plik nie może zostać zapisany
+    """
+    title = f"{result.algorithm_name} - {result.route_length:.2f}"
+    svg = route_svg(result.route, _result_coords(result, coords), title)
+    with open(output, "w", encoding="utf-8") as f:
+        f.write(svg)
+
+
+def export_route_coords(
+    result: AlgorithmResult, coords: Optional[Coords], output: str
+) -> None:
+    """
+    Zapisuje współrzędne miast trasy jako plik CSV z wierszami "x,y" w kolejności trasy
+    (bez nagłówka). Pierwsze miasto powtarzane jest na końcu, aby zamknąć cykl -
+    plik można bezpośrednio narysować np. w matplotlib lub gnuplot.
+
+    Args:
+        result: Wynik algorytmu z trasą do wyeksportowania
+        coords: Współrzędne miast (indeks = numer miasta); None - współrzędne
+                zapisane w wyniku (with_coords)
+        output: Ścieżka do pliku CSV
+
+    Raises:
+        ValueError: Jeśli indeks miasta w trasie nie ma współrzędnych lub nie podano
+                    współrzędnych, a wynik ich nie zawiera
+        IOError: Jeśli plik nie może zostać zapisany
+    """
+    coords = _result_coords(result, coords)
+    _check_route_coords(result.route, coords)
+
+    path = result.route + result.route[:1]
+    with open(output, "w", encoding="utf-8", newline="") as f:
+        writer = csv.writer(f)
+        for city in path:
+            x, y = coords[city]
+            writer.writerow([x, y])
+
+
+def export_route_frames(routes: List[Tour], coords: Coords, output_dir: str) -> List[str]:
+    """
+    Zapisuje kolejne trasy pośrednie jako ponumerowane pliki SVG (frame_0000.svg,
+    frame_0001.svg, ...), np. do złożenia animacji GIF zmian trasy w trakcie działania.
+
+    Wszystkie trasy sprawdzane są przed zapisem pierwszej klatki, więc przy błędzie
+    w katalogu nie zostają częściowe wyniki.
+
+    Args:
+        routes: Trasy pośrednie w kolejności iteracji
+        coords: Współrzędne miast (indeks = numer miasta)
+        output_dir: Katalog na pliki klatek (tworzony, jeśli nie istnieje)
+
+    Returns:
+        List[str]: Ścieżki zapisanych plików w kolejności klatek
+
+    Raises:
+        ValueError: Jeśli liczba miast którejś trasy różni się od liczby współrzędnych
+                    lub indeks miasta nie ma współrzędnych
+        IOError: Jeśli plik nie może zostać zapisany
+    """
+    for frame, route in enumerate(routes):
+        if len(route) != len(coords):
+            raise ValueError(
+                f"Frame {frame}: route has {len(route)} cities, but {len(coords)} coords given"
+            )
+        _check_route_coords(route, coords)
+
+    directory = Path(output_dir)
+    directory.mkdir(parents=True, exist_ok=True)
+    digits = max(FRAME_NUMBER_DIGITS, len(str(len(routes) - 1)))
+    paths = []
+    for frame, route in enumerate(routes):
+        path = directory / f"frame_{frame:0{digits}d}.svg"
+        with open(path, "w", encoding="utf-8") as f:
+            f.write(route_svg(route, coords, f"{frame + 1}/{len(routes)}"))
+        paths.append(str(path))
+    return paths
+
+
+def convergence_svg(results: List[AlgorithmResult], title: str = "") -> str:
+    """
+    Buduje dokument SVG z krzywymi zbieżności (najlepsza długość po każdej iteracji)
+    wielu wyników na wspólnych osiach, z legendą wg algorithm_name.
+
+    Wyniki bez danych zbieżności są pomijane z ostrzeżeniem.
+    """
+    series = []
+    for result in results:
+        if not result.convergence:
+            print(f"Pominięto wynik {result.algorithm_name} bez danych zbieżności")
+            continue
+        series.append(result)
+
+    lines = [
+        f'<svg xmlns="http://www.w3.org/2000/svg" width="{CHART_WIDTH}" height="{CHART_HEIGHT}" '
+        f'viewBox="0 0 {CHART_WIDTH} {CHART_HEIGHT}">',
+        '<rect width="100%" height="100%" fill="white"/>',
+    ]
+    if title:
+        lines.append(
+            f'<text x="{CHART_MARGIN}" y="{CHART_MARGIN / 2}" font-size="14">{title}</text>'
+        )
+
+    left, right = CHART_MARGIN, CHART_WIDTH - CHART_MARGIN
+    top, bottom = CHART_MARGIN, CHART_HEIGHT - CHART_MARGIN
+    lines.append(
+        f'<polyline points="{left},{top} {left},{bottom} {right},{bottom}" '
+        'fill="none" stroke="black"/>'
+    )
+
+    if series:
+        max_iteration = max(len(r.convergence) for r in series)
+        values = [v for r in series for v in r.convergence]
+        min_y, max_y = min(values), max(values)
+        span_x = max(max_iteration - 1, 1)
+        span_y = (max_y - min_y) or 1.0
+
+        def point(iteration: int, value: float) -> str:
+            x = left + (iteration / span_x) * (right - left)
+            y = bottom - ((value - min_y) / span_y) * (bottom - top)
+            return f"{x:.2f},{y:.2f}"
+
+        # Opisy osi: zakres iteracji i długości tras
+        axis_labels = [
+            (left, bottom + 20, "start", "1"),
+            (right, bottom + 20, "end", str(max_iteration)),
+            (left - 5, bottom, "end", f"{min_y:.0f}"),
+            (left - 5, top + 12, "end", f"{max_y:.0f}"),
+        ]
+        for x, y, anchor, label in axis_labels:
+            lines.append(
+                f'<text x="{x}" y="{y}" font-size="12" text-anchor="{anchor}">{label}</text>'
+            )
+
+        for idx, result in enumerate(series):
+            color = SERIES_COLORS[idx % len(SERIES_COLORS)]
+            path_str = " ".join(point(i, v) for i, v in enumerate(result.convergence))
+            lines.append(
+                f'<polyline points="{path_str}" fill="none" stroke="{color}" stroke-width="1.5"/>'
+            )
+            legend_y = top + 15 + idx * 16
+            lines.append(
+                f'<line x1="{right - 150}" y1="{legend_y - 4}" x2="{right - 130}" '
+                f'y2="{legend_y - 4}" stroke="{color}" stroke-width="2"/>'
+            )
+            lines.append(
+                f'<text x="{right - 125}" y="{legend_y}" font-size="12">'
+                f"{result.algorithm_name}</text>"
+            )
+
+    lines.append("</svg>")
+    return "\n".join(lines) + "\n"
+
+
+def plot_convergence(results: List[AlgorithmResult], output: str, title: str = "") -> None:
+    """
+    Zapisuje wykres porównawczy krzywych zbieżności wyników jako plik SVG.
+
+    Args:
+        results: Wyniki do porównania (bez danych zbieżności są pomijane z ostrzeżeniem)
+        output: Ścieżka do pliku SVG
+        title: Opcjonalny tytuł wykresu
+
+    Raises:
+        IOError: Jeśli plik nie może zostać zapisany
+    """
+    svg = convergence_svg(results, title)
+    with open(output, "w", encoding="utf-8") as f:
+        f.write(svg)
@@ -0,0 +1,329 @@
+"""
+Moduł zestawień porównawczych wyników algorytmów (do sprawozdań) i testów
+istotności różnic między algorytmami.
+"""
+
+import math
+import statistics
+from dataclasses import dataclass
+from typing import Dict, List, Optional, Tuple
+
+from utils.monitoring import AlgorithmResult, ResultMonitor
+from utils.tour import lengths_match, route_edges
+
+# Do tylu par (bez różnic zerowych) p-value testu Wilcoxona liczone jest dokładnie,
+# powyżej - z przybliżenia normalnego
+WILCOXON_EXACT_MAX_PAIRS = 50
+
+
+def group_by_algorithm(results: List[AlgorithmResult]) -> Dict[str, List[AlgorithmResult]]:
+    """Grupuje wyniki wg nazwy algorytmu (zachowując kolejność pierwszego wystąpienia)."""
+    groups: Dict[str, List[AlgorithmResult]] = {}
+    for result in results:
+        groups.setdefault(result.algorithm_name, []).append(result)
+    return groups
+
+
+def markdown_table(results: List[AlgorithmResult]) -> str:
+    """
+    Buduje tabelę Markdown (GitHub) z jednym wierszem na algorytm: liczba uruchomień,
+    najlepsza i średnia długość trasy, średni czas wykonania i średnia liczba iteracji.
+    """
+    lines = [
+        "| Algorytm | Uruchomienia | Najlepsza długość | Średnia długość | Średni czas [s] | Średnio iteracji |",
+        "|---|---:|---:|---:|---:|---:|",
+    ]
+    for name, group in group_by_algorithm(results).items():
+        summary = AlgorithmResult.summarize(group)
+        mean_iterations = statistics.mean(r.iterations for r in group)
+        lines.append(
+            f"| {name} | {summary.count} | {summary.best_length:.2f} | "
+            f"{summary.mean_length:.2f} | {summary.mean_execution_time_s:.3f} | "
+            f"{mean_iterations:.1f} |"
+        )
+    return "\n".join(lines) + "\n"
+
+
+def export_markdown(results: List[AlgorithmResult], output: str) -> None:
+    """
+    Zapisuje tabelę porównawczą wyników w formacie Markdown.
+
+    Args:
+        results: Wyniki do zestawienia
+        output: Ścieżka do pliku .md
+
+    Raises:
+        IOError: Jeśli plik nie może zostać zapisany
+    """
+    with open(output, "w", encoding="utf-8") as f:
+        f.write(markdown_table(results))
+
+
+@dataclass
+class WilcoxonResult:
+    """Wynik testu znakowanego rangowego Wilcoxona dla prób zależnych."""
+
+    # Statystyka testu: min(w_plus, w_minus)
+    statistic: float
+    # Dwustronna wartość p
+    p_value: float
+    # Suma rang dodatnich różnic a - b (a dłuższe niż b)
+    w_plus: float
+    # Suma rang ujemnych różnic a - b (a krótsze niż b)
+    w_minus: float
+    # Liczba par po odrzuceniu różnic zerowych
+    pairs: int
+
+
+def _average_ranks(values: List[float]) -> List[float]:
+    """Rangi 1..n wartości; remisy otrzymują średnią rangę."""
+    order = sorted(range(len(values)), key=lambda idx: values[idx])
+    ranks = [0.0] * len(values)
+    start = 0
+    while start < len(order):
+        end = start
+        while end + 1 < len(order) and values[order[end + 1]] == values[order[start]]:
+            end += 1
+        for k in range(start, end + 1):
+            ranks[order[k]] = (start + end) / 2 + 1
+        start = end + 1
+    return ranks
+
+
+def wilcoxon_signed_rank(a: List[float], b: List[float]) -> WilcoxonResult:
+    """
+    Test znakowany rangowy Wilcoxona dla par (a[i], b[i]), np. długości tras dwóch
+    algorytmów na tych samych instancjach. Hipoteza zerowa: rozkład różnic a - b
+    jest symetryczny względem zera.
+
+    Pary z zerową różnicą są pomijane, a remisy |a - b| dostają średnie rangi.
+    Dla co najwyżej WILCOXON_EXACT_MAX_PAIRS par p-value jest dokładne (rozkład
+    sumy rang przy danych rangach), dla większej liczby - z przybliżenia normalnego
+    z poprawką na remisy i ciągłość. Bez niezerowych różnic zwraca p_value = 1.0.
+
+    Raises:
+        ValueError: Jeśli a i b mają różne długości
+    """
+    if len(a) != len(b):
+        raise ValueError(f"Samples must have equal length, got {len(a)} and {len(b)}")
+
+    diffs = [x - y for x, y in zip(a, b) if x != y]
+    n = len(diffs)
+    if n == 0:
+        return WilcoxonResult(statistic=0.0, p_value=1.0, w_plus=0.0, w_minus=0.0, pairs=0)
+
+    ranks = _average_ranks([abs(d) for d in diffs])
+    w_plus = sum(r for r, d in zip(ranks, diffs) if d > 0)
+    w_minus = sum(r for r, d in zip(ranks, diffs) if d < 0)
+    statistic = min(w_plus, w_minus)
+
+    if n <= WILCOXON_EXACT_MAX_PAIRS:
+        # Rangi średnie są wielokrotnościami 1/2 - po podwojeniu są całkowite.
+        # counts[s] = liczba przypisań znaków, dla których podwojona suma rang dodatnich = s
+        doubled = [int(round(2 * r)) for r in ranks]
+        counts = [0] * (sum(doubled) + 1)
+        counts[0] = 1
+        for rank in doubled:
+            for total in range(len(counts) - 1, rank - 1, -1):
+                counts[total] += counts[total - rank]
+        threshold = int(round(2 * statistic))
+        p_value = 2 * sum(counts[: threshold + 1]) / 2**n
+    else:
+        mean = n * (n + 1) / 4
+        tie_sizes: Dict[float, int] = {}
+        for r in ranks:
+            tie_sizes[r] = tie_sizes.get(r, 0) + 1
+        tie_correction = sum(t**3 - t for t in tie_sizes.values()) / 48
+        sd = math.sqrt(n * (n + 1) * (2 * n + 1) / 24 - tie_correction)
+        z = (abs(statistic - mean) - 0.5) / sd if sd > 0 else 0.0
+        p_value = math.erfc(max(z, 0.0) / math.sqrt(2))
+
+    return WilcoxonResult(
+        statistic=statistic,
+        p_value=min(1.0, p_value),
+        w_plus=w_plus,
+        w_minus=w_minus,
+        pairs=n,
+    )
+
+
+def paired_route_lengths(
+    a: List[AlgorithmResult], b: List[AlgorithmResult]
+) -> Tuple[List[float], List[float]]:
+    """
+    Paruje długości tras dwóch grup wyników wg instancji (instance_name):
+    k-te uruchomienie na danej instancji w grupie a z k-tym w grupie b.
+
+    Raises:
+        ValueError: Jeśli dla którejś instancji grupy mają różną liczbę wyników
+    """
+
+    def by_instance(results: List[AlgorithmResult]) -> Dict[Optional[str], List[float]]:
+        groups: Dict[Optional[str], List[float]] = {}
+        for result in results:
+            groups.setdefault(result.instance_name, []).append(result.route_length)
+        return groups
+
+    groups_a, groups_b = by_instance(a), by_instance(b)
+    if set(groups_a) != set(groups_b):
+        raise ValueError(
+            f"Groups cover different instances: {sorted(map(str, groups_a))} "
+            f"vs {sorted(map(str, groups_b))}"
+        )
+
+    lengths_a: List[float] = []
+    lengths_b: List[float] = []
+    for instance, values_a in groups_a.items():
+        values_b = groups_b[instance]
+        if len(values_a) != len(values_b):
+            raise ValueError(
+                f"Instance {instance}: {len(values_a)} vs {len(values_b)} results"
+            )
+        lengths_a += values_a
+        lengths_b += values_b
+    return lengths_a, lengths_b
+
+
+def compare_experiments(
+    monitor: ResultMonitor, experiment_a: str, experiment_b: str
+) -> WilcoxonResult:
+    """
+    Test Wilcoxona dla długości tras dwóch eksperymentów (experiment_id), sparowanych
+    wg instancji (patrz paired_route_lengths).
+
+    Raises:
+        ValueError: Jeśli wyników nie da się sparować lub któryś plik jest uszkodzony
+    """
+    lengths_a, lengths_b = paired_route_lengths(
+        monitor.list_by_experiment(experiment_a), monitor.list_by_experiment(experiment_b)
+    )
+    return wilcoxon_signed_rank(lengths_a, lengths_b)
+
+
+def pairwise_winrate(results: List[AlgorithmResult]) -> Dict[Tuple[str, str], float]:
+    """
+    Tabela turniejowa algorytmów: dla każdej pary (A, B) ułamek wspólnych instancji
+    (instance_name), na których najlepszy wynik A jest krótszy od najlepszego wyniku B.
+    Remis (lengths_match) liczy się jako pół zwycięstwa, więc
+    winrate[(A, B)] + winrate[(B, A)] = 1. Pary bez wspólnych instancji są pomijane.
+
+    Wyniki bez instance_name są pomijane (z komunikatem).
+    """
+    best: Dict[str, Dict[str, float]] = {}
+    skipped = 0
+    for result in results:
+        if result.instance_name is None:
+            skipped += 1
+            continue
+        per_algorithm = best.setdefault(result.instance_name, {})
+        name = result.algorithm_name
+        per_algorithm[name] = min(per_algorithm.get(name, math.inf), result.route_length)
+    if skipped:
+        print(f"Pominięto wyniki bez instance_name: {skipped}")
+
+    algorithms = list(group_by_algorithm(results))
+    winrate: Dict[Tuple[str, str], float] = {}
+    for a in algorithms:
+        for b in algorithms:
+            if a == b:
+                continue
+            shared = [lengths for lengths in best.values() if a in lengths and b in lengths]
+            if not shared:
+                continue
+            wins = 0.0
+            for lengths in shared:
+                if lengths_match(lengths[a], lengths[b]):
+                    wins += 0.5
+                elif lengths[a] < lengths[b]:
+                    wins += 1.0
+            winrate[(a, b)] = wins / len(shared)
+    return winrate
+
+
+def winrate_table(results: List[AlgorithmResult]) -> str:
+    """
+    Tabela Markdown pairwise_winrate: wiersz A, kolumna B to ułamek instancji,
+    na których A wygrywa z B ("-" na przekątnej i dla par bez wspólnych instancji).
+    """
+    winrate = pairwise_winrate(results)
+    algorithms = list(group_by_algorithm(results))
+    lines = [
+        "| Algorytm | " + " | ".join(algorithms) + " |",
+        "|---|" + "---:|" * len(algorithms),
+    ]
+    for a in algorithms:
+        cells = [
+            f"{winrate[(a, b)]:.2f}" if (a, b) in winrate else "-" for b in algorithms
+        ]
+        lines.append(f"| {a} | " + " | ".join(cells) + " |")
+    return "\n".join(lines) + "\n"
+
+
+@dataclass(order=True)
+class RobustnessEntry:
+    """Najgorszy przypadek algorytmu na zbiorze instancji (sortowanie wg worst_gap)."""
+
+    # Największa (po instancjach) średnia optimality_gap w procentach
+    worst_gap: float
+    algorithm: str
+    # Instancja, na której wystąpił najgorszy przypadek
+    worst_instance: str
+    # Średnia optimality_gap po instancjach (dla porównania z najgorszym przypadkiem)
+    mean_gap: float
+    # Liczba instancji, na których uruchomiono algorytm
+    instances: int
+
+
+def robustness_ranking(results: List[AlgorithmResult]) -> List[RobustnessEntry]:
+    """
+    Ranking odporności algorytmów: dla każdego algorytmu średnia optimality_gap
+    jego uruchomień na każdej instancji (instance_name), a z nich najgorsza.
+    Lista jest posortowana od najmniejszej najgorszej luki (najbardziej niezawodny
+    algorytm pierwszy).
+
+    Wyniki bez instance_name lub bez metryki optimality_gap (nieznane optimum,
+    patrz AlgorithmResult.with_known_optimum) są pomijane (z komunikatem).
+    """
+    gaps: Dict[str, Dict[str, List[float]]] = {}
+    skipped = 0
+    for result in results:
+        gap = result.additional_metrics.get("optimality_gap")
+        if result.instance_name is None or gap is None:
+            skipped += 1
+            continue
+        per_instance = gaps.setdefault(result.algorithm_name, {})
+        per_instance.setdefault(result.instance_name, []).append(gap)
+    if skipped:
+        print(f"Pominięto wyniki bez instance_name lub optimality_gap: {skipped}")
+
+    ranking = []
+    for algorithm, per_instance in gaps.items():
+        means = {instance: statistics.mean(values) for instance, values in per_instance.items()}
+        worst_instance = max(means, key=lambda instance: means[instance])
+        ranking.append(
+            RobustnessEntry(
+                worst_gap=means[worst_instance],
+                algorithm=algorithm,
+                worst_instance=worst_instance,
+                mean_gap=statistics.mean(means.values()),
+                instances=len(means),
+            )
+        )
+    ranking.sort()
+    return ranking
+
+
+def edge_frequency(results: List[AlgorithmResult]) -> Dict[Tuple[int, int], int]:
+    """
+    Liczy, w ilu wynikach występuje każda krawędź (route_edges, pary (mniejsze,
+    większe miasto)) - najczęstsze krawędzie dobrych rozwiązań tworzą "szkielet"
+    trasy i są kandydatami do ustalenia w zawężonym przeszukiwaniu, np.:
+    sorted(frequency.items(), key=lambda item: -item[1])[:20].
+
+    Otwarte trasy (is_closed = False) liczone są bez krawędzi powrotu.
+    """
+    frequency: Dict[Tuple[int, int], int] = {}
+    for result in results:
+        for edge in route_edges(result.route, result.is_closed):
+            frequency[edge] = frequency.get(edge, 0) + 1
+    return frequency
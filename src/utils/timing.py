@@ -5,7 +5,10 @@ Ten moduł zapewnia narzędzia do precyzyjnego mierzenia czasu wykonania kodu.
 """
 
 import time
-from typing import Callable, Tuple, TypeVar
+from typing import TYPE_CHECKING, Callable, Optional, Tuple, TypeVar
+
+if TYPE_CHECKING:
+    from utils.monitoring import AlgorithmResult
 
 # TypeVar do zachowania typu zwracanego przez funkcję
 T = TypeVar('T')
@@ -39,3 +42,52 @@ def measure_execution_time(func: Callable[[], T]) -> Tuple[T, float]:
     
     return result, execution_time_s
 
+
+def timed(func: Callable[[], "AlgorithmResult"]) -> "AlgorithmResult":
+    """
+    Wykonuje funkcję budującą wynik algorytmu i ustawia w nim zmierzony czas wykonania.
+
+    Czas ustawiany jest przez set_execution_time i nadpisuje wartość ustawioną
+    wewnątrz funkcji. Aby mierzyć wyłącznie obliczenia, funkcja nie powinna
+    wczytywać danych ani zapisywać wyników.
+
+    Args:
+        func: Funkcja bez argumentów zwracająca AlgorithmResult
+
+    Returns:
+        AlgorithmResult: Wynik zwrócony przez func z ustawionym execution_time_s
+
+    Example:
+        >>> result = timed(lambda: build_result(distance_matrix))
+        >>> print(f"Obliczenia zajęły {result.execution_time_s:.3f} s")
+    """
+    result, execution_time_s = measure_execution_time(func)
+    result.set_execution_time(execution_time_s)
+    return result
+
+
+class Deadline:
+    """
+    Limit czasu rzeczywistego dla pętli algorytmu, liczony od utworzenia obiektu.
+
+    Algorytmy iteracyjne sprawdzają expired() na początku każdej iteracji zewnętrznej
+    (poza pierwszą, aby zawsze zwrócić jakąś trasę) i kończą się z najlepszym
+    dotychczasowym wynikiem.
+
+    Example:
+        >>> deadline = Deadline(config.max_duration_s)
+        >>> for iteration in range(1, config.iterations + 1):
+        ...     if iteration > 1 and deadline.expired():
+        ...         break
+    """
+
+    def __init__(self, max_duration_s: Optional[float] = None):
+        """
+        Args:
+            max_duration_s: Limit czasu w sekundach (None - brak limitu)
+        """
+        self._end = None if max_duration_s is None else time.perf_counter() + max_duration_s
+
+    def expired(self) -> bool:
+        """Zwraca True, jeśli limit czasu został przekroczony."""
+        return self._end is not None and time.perf_counter() >= self._end
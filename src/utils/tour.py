@@ -1,21 +1,353 @@
 import random
-from typing import List
+from typing import Dict, List, Optional, Set, Tuple
 
 from utils.data_types import DistanceMatrix, Tour
 
+# Domyślna względna tolerancja porównywania długości tras (ułamek większej z długości)
+DEFAULT_LENGTH_TOLERANCE = 1e-6
 
-def random_tour(n: int) -> Tour:
-    """Losowa permutacja miast 0..n-1."""
+
+class RouteError(ValueError):
+    """Bazowy błąd niepoprawnej trasy."""
+
+
+class WrongLength(RouteError):
+    """Trasa ma inną liczbę miast niż oczekiwana."""
+
+    def __init__(self, expected: int, actual: int):
+        super().__init__(f"Route has {actual} cities, expected {expected}")
+        self.expected = expected
+        self.actual = actual
+
+
+class DuplicateCity(RouteError):
+    """Miasto występuje w trasie więcej niż raz."""
+
+    def __init__(self, city: int):
+        super().__init__(f"City {city} appears more than once in route")
+        self.city = city
+
+
+class IndexOutOfRange(RouteError):
+    """Indeks miasta spoza zakresu 0..num_cities-1."""
+
+    def __init__(self, city: int):
+        super().__init__(f"City index {city} is out of range")
+        self.city = city
+
+
+def validate_route(tour: Tour, num_cities: int) -> None:
+    """
+    Sprawdza, czy trasa jest permutacją miast 0..num_cities-1.
+
+    Raises:
+        IndexOutOfRange: Jeśli trasa zawiera indeks spoza zakresu
+        DuplicateCity: Jeśli miasto występuje więcej niż raz
+        WrongLength: Jeśli liczba miast w trasie jest różna od num_cities
+    """
+    seen = set()
+    for city in tour:
+        if city < 0 or city >= num_cities:
+            raise IndexOutOfRange(city)
+        if city in seen:
+            raise DuplicateCity(city)
+        seen.add(city)
+    if len(tour) != num_cities:
+        raise WrongLength(num_cities, len(tour))
+
+
+def repair_route(route: Tour, num_cities: int) -> int:
+    """
+    Naprawia w miejscu trasę zwróconą przez błędny operator krzyżowania/mutacji tak,
+    aby była permutacją miast 0..num_cities-1. Pierwsze wystąpienie każdego miasta
+    zostaje na swojej pozycji, a powtórzenia i indeksy spoza zakresu zastępowane są
+    brakującymi miastami w kolejności rosnącej. Nadmiarowe pozycje są usuwane,
+    a brakujące miasta, dla których zabrakło pozycji, dopisywane na końcu.
+
+    Dla poprawnej trasy nic nie zmienia.
+
+    Returns:
+        Liczba zastąpionych, usuniętych lub dopisanych pozycji (0 dla poprawnej trasy)
+    """
+    seen = [False] * num_cities
+    invalid_positions = []
+    for idx, city in enumerate(route):
+        if 0 <= city < num_cities and not seen[city]:
+            seen[city] = True
+        else:
+            invalid_positions.append(idx)
+
+    missing = [city for city in range(num_cities) if not seen[city]]
+    if not invalid_positions and not missing:
+        return 0
+
+    for idx, city in zip(invalid_positions, missing):
+        route[idx] = city
+    surplus = invalid_positions[len(missing):]
+    for idx in reversed(surplus):
+        del route[idx]
+    route.extend(missing[len(invalid_positions):])
+    return max(len(invalid_positions), len(missing))
+
+
+def random_tour(n: int, rng: Optional[random.Random] = None) -> Tour:
+    """
+    Losowa permutacja miast 0..n-1 (z podanego generatora lub globalnego).
+    Tasowanie Fishera-Yatesa (random.shuffle) - generator z tym samym ziarnem
+    daje tę samą trasę.
+    """
     tour = list(range(n))
-    random.shuffle(tour)
+    (rng or random).shuffle(tour)
     return tour
 
-def tour_length(tour: Tour, distance_matrix: DistanceMatrix) -> float:
-    """Długość cyklu TSP dla danej trasy (zamkniętej)."""
+
+def double_bridge(tour: Tour, rng: Optional[random.Random] = None) -> Tour:
+    """
+    Perturbacja double-bridge (ruch 4-opt): trasa A B C D, podzielona w trzech
+    losowych miejscach na niepuste fragmenty, zamieniana jest na A C B D.
+    Żaden fragment nie jest odwracany, więc pojedynczy ruch 2-opt nie cofa tej zmiany.
+
+    Zwraca nową trasę (permutację tych samych miast); dla tras krótszych niż 4 miasta
+    zwraca kopię.
+    """
+    n = len(tour)
+    if n < 4:
+        return tour.copy()
+    p1, p2, p3 = sorted((rng or random).sample(range(1, n), 3))
+    return tour[:p1] + tour[p2:p3] + tour[p1:p2] + tour[p3:]
+
+
+def lengths_match(
+    a: float,
+    b: float,
+    epsilon: Optional[float] = None,
+    relative_tolerance: float = DEFAULT_LENGTH_TOLERANCE,
+) -> bool:
+    """
+    Porównuje dwie długości tras z tolerancją na błędy zaokrągleń.
+
+    Args:
+        a, b: Porównywane długości
+        epsilon: Tolerancja bezwzględna; None oznacza tolerancję względną
+        relative_tolerance: Tolerancja względna (ułamek większej z długości),
+                            używana, gdy epsilon jest None
+    """
+    if epsilon is None:
+        epsilon = relative_tolerance * max(abs(a), abs(b))
+    return abs(a - b) <= epsilon
+
+
+def tour_length(
+    tour: Tour,
+    distance_matrix: DistanceMatrix,
+    denormalize: bool = False,
+    closed: bool = True,
+) -> float:
+    """
+    Długość cyklu TSP dla danej trasy (zamkniętej), z uwzględnieniem kierunku krawędzi.
+    Przy closed=False trasa jest otwartą ścieżką z tour[0] (np. z magazynu) do tour[-1]
+    i krawędź powrotu do miasta startowego jest pomijana.
+    Przy denormalize=True długość jest przeliczana na jednostki oryginalnej macierzy
+    (dla macierzy z DistanceMatrix.normalized).
+    """
     n = len(tour)
     total = 0.0
-    for i in range(n):
+    for i in range(n if closed else n - 1):
         a = tour[i]
         b = tour[(i + 1) % n]  # powrót do miasta startowego
         total += distance_matrix[a][b]
-    return total
\ No newline at end of file
+    return distance_matrix.denormalize(total) if denormalize else total
+
+
+def edge_lengths(
+    tour: Tour, distance_matrix: DistanceMatrix, closed: bool = True
+) -> List[float]:
+    """
+    Długości kolejnych krawędzi trasy: tour[0] -> tour[1], ..., tour[-1] -> tour[0]
+    (ostatnia to krawędź zamykająca cykl, pomijana przy closed=False).
+    Suma listy to tour_length.
+    """
+    n = len(tour)
+    count = n if closed else max(n - 1, 0)
+    return [distance_matrix[tour[i]][tour[(i + 1) % n]] for i in range(count)]
+
+
+class TrackedTour:
+    """
+    Trasa z zapamiętaną długością, przeliczaną ponownie po zmianie macierzy przez
+    DistanceMatrix.update_edge (dynamiczny TSP). Solver trzymający bieżącą najlepszą
+    trasę jako TrackedTour nigdy nie odczyta długości sprzed aktualizacji krawędzi.
+
+    Bezpośrednie przypisania matrix[a][b] = ... nie zmieniają version - należy wtedy
+    wywołać invalidate().
+    """
+
+    def __init__(self, tour: Tour, distance_matrix: DistanceMatrix):
+        self.distance_matrix = distance_matrix
+        self.set_tour(tour)
+
+    def set_tour(self, tour: Tour, length: Optional[float] = None) -> None:
+        """Podmienia trasę; podana długość musi odpowiadać bieżącej macierzy."""
+        self.tour = tour
+        self._length = length
+        self._version = self.distance_matrix.version
+
+    def invalidate(self) -> None:
+        """Wymusza przeliczenie długości przy następnym odczycie."""
+        self._length = None
+
+    @property
+    def is_stale(self) -> bool:
+        """Czy macierz zmieniła się od ostatniego wyliczenia długości."""
+        return self._length is None or self._version != self.distance_matrix.version
+
+    @property
+    def length(self) -> float:
+        """Długość trasy wg bieżącej macierzy (przeliczana, jeśli jest nieaktualna)."""
+        if self.is_stale:
+            self._length = tour_length(self.tour, self.distance_matrix)
+            self._version = self.distance_matrix.version
+        return self._length
+
+
+def rotate_to_start(tour: Tour, city: int = 0) -> Tour:
+    """
+    Zwraca trasę obróconą tak, aby zaczynała się od podanego miasta (kierunek
+    i długość trasy bez zmian). Trasa bez tego miasta zwracana jest bez zmian.
+    """
+    if city not in tour:
+        return tour.copy()
+    start = tour.index(city)
+    return tour[start:] + tour[:start]
+
+
+def canonicalize_route(tour: Tour) -> Tour:
+    """
+    Zwraca kanoniczną postać trasy, jednakową dla wszystkich jej obrotów i odbić:
+    trasa zaczyna się od najmniejszego miasta (0 dla pełnej permutacji), a drugim
+    miastem jest mniejszy z jego dwóch sąsiadów.
+
+    Uwaga: dla macierzy asymetrycznych odbicie trasy może mieć inną długość.
+    """
+    n = len(tour)
+    if n <= 2:
+        return sorted(tour)
+    start = tour.index(min(tour))
+    rotated = tour[start:] + tour[:start]
+    if rotated[-1] < rotated[1]:
+        rotated = [rotated[0]] + rotated[:0:-1]
+    return rotated
+
+
+def _check_same_length(a: Tour, b: Tour) -> None:
+    if len(a) != len(b):
+        raise ValueError(f"Routes must have equal length, got {len(a)} and {len(b)}")
+
+
+def route_distance(a: Tour, b: Tour) -> int:
+    """
+    Odległość pozycyjna (Hamminga) między trasami: liczba pozycji, na których
+    różnią się ich postacie kanoniczne (obroty i odbicia tej samej trasy dają 0).
+
+    Raises:
+        ValueError: Jeśli trasy mają różne długości
+    """
+    _check_same_length(a, b)
+    return sum(x != y for x, y in zip(canonicalize_route(a), canonicalize_route(b)))
+
+
+def mean_route_distance(routes: List[Tour]) -> float:
+    """
+    Średnia odległość route_distance po wszystkich parach tras (różnorodność populacji).
+
+    Liczona w czasie O(liczba_tras * n): dla każdej pozycji postaci kanonicznych
+    zliczane są pary tras, które mają na niej różne miasta. Dla mniej niż dwóch tras
+    zwraca 0.0.
+
+    Raises:
+        ValueError: Jeśli trasy mają różne długości
+    """
+    count = len(routes)
+    if count < 2:
+        return 0.0
+    for route in routes[1:]:
+        _check_same_length(routes[0], route)
+
+    canonical = [canonicalize_route(route) for route in routes]
+    pairs = count * (count - 1) // 2
+    differing = 0
+    for position in range(len(canonical[0])):
+        occurrences: Dict[int, int] = {}
+        for route in canonical:
+            occurrences[route[position]] = occurrences.get(route[position], 0) + 1
+        differing += pairs - sum(k * (k - 1) // 2 for k in occurrences.values())
+    return differing / pairs
+
+
+def route_edges(route: Tour, closed: bool = True) -> Set[Tuple[int, int]]:
+    """
+    Zbiór (nieskierowanych) krawędzi trasy jako pary (mniejsze, większe miasto),
+    z krawędzią zamykającą cykl, chyba że closed=False (otwarta ścieżka).
+    Obroty i odbicia trasy dają ten sam zbiór.
+    """
+    n = len(route)
+    if n < 2:
+        return set()
+    edges = set()
+    for i in range(n if closed else n - 1):
+        a, b = route[i], route[(i + 1) % n]
+        edges.add((a, b) if a < b else (b, a))
+    return edges
+
+
+def edge_distance(a: Tour, b: Tour) -> int:
+    """
+    Odległość krawędziowa między trasami: liczba (nieskierowanych) krawędzi trasy a,
+    których nie ma w trasie b.
+
+    Raises:
+        ValueError: Jeśli trasy mają różne długości
+    """
+    _check_same_length(a, b)
+    n = len(a)
+    edges_b = {frozenset((b[i], b[(i + 1) % n])) for i in range(n)}
+    return sum(frozenset((a[i], a[(i + 1) % n])) not in edges_b for i in range(n))
+
+
+def route_swaps(source: Tour, target: Tour) -> List[Tuple[int, int]]:
+    """
+    Zwraca listę zamian pozycji (i, j), która przekształca trasę source w target
+    (co najwyżej n - 1 zamian; dla niemal identycznych tras - kilka).
+
+    Raises:
+        ValueError: Jeśli trasy nie są permutacjami tych samych miast
+    """
+    if sorted(source) != sorted(target):
+        raise ValueError("Routes must be permutations of the same cities")
+    current = source.copy()
+    positions = {city: idx for idx, city in enumerate(current)}
+    swaps = []
+    for i, city in enumerate(target):
+        if current[i] == city:
+            continue
+        j = positions[city]
+        positions[current[i]], positions[city] = j, i
+        current[i], current[j] = current[j], current[i]
+        swaps.append((i, j))
+    return swaps
+
+
+def apply_swaps(route: Tour, swaps: List[Tuple[int, int]]) -> Tour:
+    """
+    Zwraca kopię trasy po wykonaniu kolejnych zamian pozycji (odwrotność route_swaps).
+
+    Raises:
+        ValueError: Jeśli pozycja zamiany jest spoza trasy
+    """
+    result = route.copy()
+    n = len(result)
+    for i, j in swaps:
+        if not (0 <= i < n and 0 <= j < n):
+            raise ValueError(f"Swap ({i}, {j}) out of range for route of {n} cities")
+        result[i], result[j] = result[j], result[i]
+    return result
@@ -0,0 +1,338 @@
+"""
+Moduł wczytywania instancji TSP w formacie TSPLIB (.tsp).
+
+Obsługiwana jest sekcja NODE_COORD_SECTION z typami odległości EUC_2D oraz GEO,
+jawne macierze odległości (EDGE_WEIGHT_TYPE: EXPLICIT, sekcja EDGE_WEIGHT_SECTION
+w formatach FULL_MATRIX, UPPER_ROW i LOWER_DIAG_ROW), a także pliki tras
+optymalnych (.opt.tour) z sekcją TOUR_SECTION.
+"""
+
+import math
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import List, Optional, Tuple
+
+from utils.data_types import DistanceMatrix, RoundingMode, Tour
+
+# Typy odległości obsługiwane przez parser
+SUPPORTED_EDGE_WEIGHT_TYPES = ("EUC_2D", "GEO", "EXPLICIT")
+
+# Formaty sekcji EDGE_WEIGHT_SECTION obsługiwane dla EDGE_WEIGHT_TYPE: EXPLICIT
+SUPPORTED_EDGE_WEIGHT_FORMATS = ("FULL_MATRIX", "UPPER_ROW", "LOWER_DIAG_ROW")
+
+
+class TsplibError(ValueError):
+    """Błąd wczytywania lub interpretacji pliku TSPLIB."""
+
+
+@dataclass
+class TspInstance:
+    """Instancja TSP wczytana z pliku TSPLIB."""
+
+    name: str
+    edge_weight_type: str
+    # Współrzędne miast, indeksowane od 0 (węzeł 1 z pliku to indeks 0)
+    coords: List[Tuple[float, float]] = field(default_factory=list)
+    comment: str = ""
+    # Jawna macierz odległości (tylko dla EDGE_WEIGHT_TYPE: EXPLICIT)
+    weights: Optional[DistanceMatrix] = None
+
+    def dimension(self) -> int:
+        """Zwraca liczbę miast w instancji."""
+        if self.weights is not None:
+            return len(self.weights)
+        return len(self.coords)
+
+    def distance_matrix(self) -> DistanceMatrix:
+        """
+        Buduje macierz odległości zgodnie z konwencją TSPLIB.
+
+        EUC_2D: odległość euklidesowa zaokrąglona do najbliższej liczby całkowitej.
+        GEO: odległość geograficzna w kilometrach (wzór z dokumentacji TSPLIB).
+        EXPLICIT: kopia macierzy wczytanej z EDGE_WEIGHT_SECTION.
+        """
+        n = self.dimension()
+        if self.edge_weight_type == "EXPLICIT":
+            if self.weights is None:
+                raise TsplibError("EXPLICIT instance has no edge weights")
+            return DistanceMatrix([row.copy() for row in self.weights])
+        if self.edge_weight_type == "EUC_2D":
+            distance_fn = _euc_2d
+        elif self.edge_weight_type == "GEO":
+            distance_fn = _geo
+        else:
+            raise TsplibError(f"Unsupported EDGE_WEIGHT_TYPE: {self.edge_weight_type}")
+
+        matrix = [[0.0] * n for _ in range(n)]
+        for i in range(n):
+            for j in range(i + 1, n):
+                d = distance_fn(self.coords[i], self.coords[j])
+                matrix[i][j] = d
+                matrix[j][i] = d
+        distance_matrix = DistanceMatrix(matrix)
+        if self.edge_weight_type == "EUC_2D":
+            distance_matrix.rounding = RoundingMode.NEAREST
+        return distance_matrix
+
+
+def _nint(x: float) -> int:
+    """Zaokrąglenie do najbliższej liczby całkowitej (jak nint w TSPLIB)."""
+    return int(x + 0.5)
+
+
+def _euc_2d(a: Tuple[float, float], b: Tuple[float, float]) -> float:
+    return float(_nint(math.hypot(a[0] - b[0], a[1] - b[1])))
+
+
+def _geo_radians(x: float) -> float:
+    # Współrzędne GEO zapisane są jako STOPNIE.MINUTY
+    pi = 3.141592
+    deg = int(x)
+    minutes = x - deg
+    return pi * (deg + 5.0 * minutes / 3.0) / 180.0
+
+
+def _geo(a: Tuple[float, float], b: Tuple[float, float]) -> float:
+    rrr = 6378.388
+    lat_a, lon_a = _geo_radians(a[0]), _geo_radians(a[1])
+    lat_b, lon_b = _geo_radians(b[0]), _geo_radians(b[1])
+    q1 = math.cos(lon_a - lon_b)
+    q2 = math.cos(lat_a - lat_b)
+    q3 = math.cos(lat_a + lat_b)
+    return float(int(rrr * math.acos(0.5 * ((1.0 + q1) * q2 - (1.0 - q1) * q3)) + 1.0))
+
+
+def _weights_matrix(values: List[float], dimension: int, edge_weight_format: str) -> DistanceMatrix:
+    """
+    Buduje pełną macierz odległości z wartości sekcji EDGE_WEIGHT_SECTION.
+
+    Raises:
+        TsplibError: Jeśli liczba wartości nie odpowiada formatowi i wymiarowi
+    """
+    n = dimension
+    expected = {
+        "FULL_MATRIX": n * n,
+        "UPPER_ROW": n * (n - 1) // 2,
+        "LOWER_DIAG_ROW": n * (n + 1) // 2,
+    }[edge_weight_format]
+    if len(values) != expected:
+        raise TsplibError(
+            f"EDGE_WEIGHT_SECTION has {len(values)} values, "
+            f"expected {expected} for {edge_weight_format} with DIMENSION {n}"
+        )
+
+    if edge_weight_format == "FULL_MATRIX":
+        return DistanceMatrix([values[i * n:(i + 1) * n] for i in range(n)])
+
+    matrix = [[0.0] * n for _ in range(n)]
+    position = 0
+    for i in range(n):
+        if edge_weight_format == "UPPER_ROW":
+            columns = range(i + 1, n)
+        else:
+            columns = range(i + 1)
+        for j in columns:
+            matrix[i][j] = values[position]
+            matrix[j][i] = values[position]
+            position += 1
+    return DistanceMatrix(matrix)
+
+
+def parse_tsp(path: Path) -> TspInstance:
+    """
+    Wczytuje instancję TSP z pliku TSPLIB.
+
+    Args:
+        path: Ścieżka do pliku .tsp
+
+    Returns:
+        TspInstance: Nazwa, typ odległości i współrzędne miast (indeksy od 0)
+        lub jawna macierz odległości dla EDGE_WEIGHT_TYPE: EXPLICIT
+
+    Raises:
+        FileNotFoundError: Jeśli plik nie może zostać otwarty
+        TsplibError: Jeśli plik ma niepoprawny format, nieobsługiwany typ odległości
+                     lub liczba wartości w EDGE_WEIGHT_SECTION nie zgadza się z DIMENSION
+    """
+    try:
+        with open(path, "r", encoding="utf-8") as file:
+            lines = file.read().splitlines()
+    except FileNotFoundError:
+        raise FileNotFoundError(f"Failed to open file: {path}")
+
+    name = Path(path).stem
+    comment_lines: List[str] = []
+    edge_weight_type: Optional[str] = None
+    edge_weight_format: Optional[str] = None
+    dimension: Optional[int] = None
+    nodes: dict = {}
+    weight_values: List[float] = []
+    in_coords = False
+    in_weights = False
+    in_display = False
+
+    for line_no, raw_line in enumerate(lines, start=1):
+        line = raw_line.strip()
+        if not line or line.startswith("#"):
+            continue
+        if line == "EOF":
+            break
+
+        if in_weights:
+            try:
+                weight_values.extend(float(token) for token in line.split())
+                continue
+            except ValueError:
+                # Koniec sekcji wag (kolejna sekcja lub słowo kluczowe)
+                in_weights = False
+
+        if in_display:
+            # Współrzędne do wizualizacji nie są potrzebne do obliczeń - pomijane
+            if line.split()[0].lstrip("-").isdigit():
+                continue
+            in_display = False
+
+        if in_coords:
+            parts = line.split()
+            if len(parts) < 3:
+                # Koniec sekcji współrzędnych (np. kolejna sekcja)
+                in_coords = False
+            else:
+                try:
+                    node_id = int(parts[0])
+                    x, y = float(parts[1]), float(parts[2])
+                except ValueError:
+                    raise TsplibError(f"Line {line_no}: invalid coordinate entry '{line}'")
+                if node_id < 1:
+                    raise TsplibError(f"Line {line_no}: node ids must be 1-based, got {node_id}")
+                if node_id in nodes:
+                    raise TsplibError(f"Line {line_no}: duplicate node id {node_id}")
+                nodes[node_id] = (x, y)
+                continue
+
+        if line.startswith("NODE_COORD_SECTION"):
+            in_coords = True
+            continue
+        if line.startswith("EDGE_WEIGHT_SECTION"):
+            in_weights = True
+            continue
+        if line.startswith("DISPLAY_DATA_SECTION"):
+            in_display = True
+            continue
+
+        key, sep, value = line.partition(":")
+        if not sep:
+            raise TsplibError(f"Line {line_no}: unsupported section or entry '{line}'")
+        key, value = key.strip().upper(), value.strip()
+
+        if key == "NAME":
+            name = value
+        elif key == "COMMENT":
+            comment_lines.append(value)
+        elif key == "DIMENSION":
+            try:
+                dimension = int(value)
+            except ValueError:
+                raise TsplibError(f"Line {line_no}: invalid DIMENSION '{value}'")
+        elif key == "EDGE_WEIGHT_TYPE":
+            edge_weight_type = value.upper()
+        elif key == "EDGE_WEIGHT_FORMAT":
+            edge_weight_format = value.upper()
+        elif key == "TYPE" and value.upper() not in ("TSP", "ATSP"):
+            raise TsplibError(f"Unsupported problem TYPE: {value}")
+
+    if edge_weight_type is None:
+        raise TsplibError("Missing EDGE_WEIGHT_TYPE")
+    if edge_weight_type not in SUPPORTED_EDGE_WEIGHT_TYPES:
+        raise TsplibError(f"Unsupported EDGE_WEIGHT_TYPE: {edge_weight_type}")
+
+    if edge_weight_type == "EXPLICIT":
+        if edge_weight_format not in SUPPORTED_EDGE_WEIGHT_FORMATS:
+            raise TsplibError(f"Unsupported EDGE_WEIGHT_FORMAT: {edge_weight_format}")
+        if dimension is None:
+            raise TsplibError("Missing DIMENSION for EXPLICIT edge weights")
+        if not weight_values:
+            raise TsplibError("Missing or empty EDGE_WEIGHT_SECTION")
+        return TspInstance(
+            name=name,
+            edge_weight_type=edge_weight_type,
+            comment="\n".join(comment_lines),
+            weights=_weights_matrix(weight_values, dimension, edge_weight_format),
+        )
+
+    if not nodes:
+        raise TsplibError("Missing or empty NODE_COORD_SECTION")
+
+    count = dimension if dimension is not None else len(nodes)
+    if len(nodes) != count or set(nodes) != set(range(1, count + 1)):
+        raise TsplibError(
+            f"NODE_COORD_SECTION has {len(nodes)} nodes, expected ids 1..{count}"
+        )
+
+    # Konwersja indeksów 1..n z pliku na 0..n-1
+    coords = [nodes[node_id] for node_id in range(1, count + 1)]
+    return TspInstance(
+        name=name,
+        edge_weight_type=edge_weight_type,
+        coords=coords,
+        comment="\n".join(comment_lines),
+    )
+
+
+def parse_opt_tour(path: Path) -> Tour:
+    """
+    Wczytuje trasę optymalną z pliku TSPLIB .opt.tour.
+
+    Args:
+        path: Ścieżka do pliku .opt.tour
+
+    Returns:
+        Tour: Trasa z indeksami miast od 0 (węzeł 1 z pliku to indeks 0)
+
+    Raises:
+        FileNotFoundError: Jeśli plik nie może zostać otwarty
+        TsplibError: Jeśli brak TOUR_SECTION, sekcja nie kończy się -1
+                     lub zawiera niepoprawne indeksy
+    """
+    try:
+        with open(path, "r", encoding="utf-8") as file:
+            lines = file.read().splitlines()
+    except FileNotFoundError:
+        raise FileNotFoundError(f"Failed to open file: {path}")
+
+    tour: Tour = []
+    in_tour = False
+    terminated = False
+
+    for line_no, raw_line in enumerate(lines, start=1):
+        line = raw_line.strip()
+        if not line:
+            continue
+        if not in_tour:
+            if line.startswith("TOUR_SECTION"):
+                in_tour = True
+            elif line == "EOF":
+                break
+            continue
+
+        for token in line.split():
+            try:
+                node_id = int(token)
+            except ValueError:
+                raise TsplibError(f"Line {line_no}: invalid node id '{token}' in TOUR_SECTION")
+            if node_id == -1:
+                terminated = True
+                break
+            if node_id < 1:
+                raise TsplibError(f"Line {line_no}: node ids must be 1-based, got {node_id}")
+            tour.append(node_id - 1)
+        if terminated:
+            break
+
+    if not in_tour:
+        raise TsplibError("Missing TOUR_SECTION")
+    if not terminated:
+        raise TsplibError("TOUR_SECTION is not terminated by -1")
+    if len(set(tour)) != len(tour):
+        raise TsplibError("TOUR_SECTION contains duplicate nodes")
+    return tour